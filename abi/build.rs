@@ -1,11 +1,30 @@
 use std::process::Command;
 
+const SERDE_DERIVE: &str = "#[derive(serde::Serialize, serde::Deserialize)]";
+const TIMESTAMP_SERDE: &str = "#[serde(with = \"crate::utils::timestamp_serde\")]";
+
 fn main() -> Result<(), Box<dyn std::error::Error>> {
     tonic_build::configure()
         .out_dir("src/pb")
+        // serde on the data messages so callers can log/export them as JSON;
+        // timestamp fields go through our RFC 3339 adapter because
+        // prost_types::Timestamp has no serde impls
+        .type_attribute(".reservation.Reservation", SERDE_DERIVE)
+        .type_attribute(".reservation.ReservationInfo", SERDE_DERIVE)
+        .field_attribute(".reservation.Reservation.start", TIMESTAMP_SERDE)
+        .field_attribute(".reservation.Reservation.end", TIMESTAMP_SERDE)
+        .field_attribute(".reservation.Reservation.created_at", TIMESTAMP_SERDE)
+        .field_attribute(".reservation.Reservation.updated_at", TIMESTAMP_SERDE)
+        .field_attribute(".reservation.ReservationInfo.start", TIMESTAMP_SERDE)
+        .field_attribute(".reservation.ReservationInfo.end", TIMESTAMP_SERDE)
         .compile(&["protos/reservation.proto"], &["protos"])?;
 
-    Command::new("cargo").args(["fmt"]).output()?;
+    // best effort: CI images may not have cargo on PATH, and the generated
+    // code compiles fine unformatted
+    let _ = Command::new("cargo").args(["fmt"]).status();
+
+    // only rerun when the proto (or this script, which cargo tracks
+    // implicitly) changes, so clean checkouts don't recompile every build
     println!("cargo:rerun-if-changed=protos/reservation.proto");
     Ok(())
 }
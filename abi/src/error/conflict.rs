@@ -0,0 +1,138 @@
+use std::{convert::Infallible, fmt, str::FromStr};
+
+use chrono::{DateTime, Utc};
+use regex::Regex;
+
+/// Conflict details recovered from the Postgres exclusion-constraint
+/// diagnostic. The diagnostic carries the constraint key values (resource id
+/// and timespan) of both the rejected and the existing row; it does not
+/// include the existing reservation's id.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ReservationConflictInfo {
+    Parsed(ReservationConflict),
+    /// The raw detail string, kept when the diagnostic can't be parsed.
+    Unparsed(String),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReservationConflict {
+    /// The window the client tried to reserve.
+    pub new: ReservationWindow,
+    /// The existing window it collided with.
+    pub old: ReservationWindow,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReservationWindow {
+    pub rid: String,
+    pub start: DateTime<Utc>,
+    pub end: DateTime<Utc>,
+}
+
+impl FromStr for ReservationConflictInfo {
+    type Err = Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s.parse() {
+            Ok(conflict) => Self::Parsed(conflict),
+            Err(_) => Self::Unparsed(s.to_string()),
+        })
+    }
+}
+
+impl FromStr for ReservationConflict {
+    type Err = ();
+
+    // the detail looks like:
+    // Key (resource_id, timespan)=(room-101, ["2024-03-26 10:00:00+00","2024-03-26 12:00:00+00")) \
+    // conflicts with existing key (resource_id, timespan)=(room-101, ["2024-03-26 11:00:00+00","2024-03-26 13:00:00+00")).
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let re = Regex::new(r#"=\((?P<rid>[^,]+),\s*\["(?P<start>[^"]+)","(?P<end>[^"]+)"\)\)"#)
+            .map_err(|_| ())?;
+        let mut windows = re.captures_iter(s).map(|caps| {
+            Ok::<_, ()>(ReservationWindow {
+                rid: caps["rid"].to_string(),
+                start: parse_pg_timestamptz(&caps["start"])?,
+                end: parse_pg_timestamptz(&caps["end"])?,
+            })
+        });
+        let new = windows.next().ok_or(())??;
+        let old = windows.next().ok_or(())??;
+        Ok(Self { new, old })
+    }
+}
+
+fn parse_pg_timestamptz(s: &str) -> Result<DateTime<Utc>, ()> {
+    DateTime::parse_from_str(s, "%Y-%m-%d %H:%M:%S%#z")
+        .map(|dt| dt.with_timezone(&Utc))
+        .map_err(|_| ())
+}
+
+impl fmt::Display for ReservationConflictInfo {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Parsed(conflict) => write!(f, "{conflict}"),
+            Self::Unparsed(s) => write!(f, "{s}"),
+        }
+    }
+}
+
+impl fmt::Display for ReservationConflict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "the requested window {} conflicts with the existing window {}",
+            self.new, self.old
+        )
+    }
+}
+
+impl fmt::Display for ReservationWindow {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "[{}, {}) on resource {}",
+            self.start.to_rfc3339(),
+            self.end.to_rfc3339(),
+            self.rid
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    const DETAIL: &str = r#"Key (resource_id, timespan)=(room-101, ["2024-03-26 10:00:00+00","2024-03-26 12:00:00+00")) conflicts with existing key (resource_id, timespan)=(room-101, ["2024-03-26 11:00:00+00","2024-03-26 13:00:00+00"))."#;
+
+    #[test]
+    fn exclusion_detail_should_parse_into_both_windows() {
+        let info: ReservationConflictInfo = DETAIL.parse().unwrap();
+        let ReservationConflictInfo::Parsed(conflict) = info else {
+            panic!("expected parsed conflict");
+        };
+        assert_eq!(conflict.new.rid, "room-101");
+        assert_eq!(
+            conflict.new.start,
+            Utc.with_ymd_and_hms(2024, 3, 26, 10, 0, 0).unwrap()
+        );
+        assert_eq!(
+            conflict.old.start,
+            Utc.with_ymd_and_hms(2024, 3, 26, 11, 0, 0).unwrap()
+        );
+        assert_eq!(
+            conflict.old.end,
+            Utc.with_ymd_and_hms(2024, 3, 26, 13, 0, 0).unwrap()
+        );
+    }
+
+    #[test]
+    fn unparsable_detail_should_fall_back_to_the_raw_string() {
+        let info: ReservationConflictInfo = "something else entirely".parse().unwrap();
+        assert_eq!(
+            info,
+            ReservationConflictInfo::Unparsed("something else entirely".to_string())
+        );
+    }
+}
@@ -0,0 +1,190 @@
+mod conflict;
+
+use sqlx::postgres::PgDatabaseError;
+use thiserror::Error;
+
+pub use conflict::{ReservationConflict, ReservationConflictInfo, ReservationWindow};
+
+#[derive(Error, Debug)]
+pub enum Error {
+    #[error("database error: {0}")]
+    DbError(sqlx::Error),
+
+    #[error("could not reach the database: {0}")]
+    ConnectionFailed(sqlx::Error),
+
+    #[error("duplicate value for unique constraint {0}")]
+    UniqueViolation(String),
+
+    #[error("conflict reservation: {0}")]
+    ConflictReservation(ReservationConflictInfo),
+
+    #[error("no reservation found by the given condition")]
+    NotFound,
+
+    #[error("invalid start or end time for the reservation")]
+    InvalidTime,
+
+    #[error("invalid user id: {0}")]
+    InvalidUserId(String),
+
+    #[error("invalid resource id: {0}")]
+    InvalidResourceId(String),
+
+    #[error("invalid reservation id: {0}")]
+    InvalidReservationId(String),
+
+    #[error("invalid field: {0}")]
+    InvalidField(String),
+
+    #[error("invalid reservation status: {0}")]
+    InvalidStatus(i32),
+
+    #[error("reservation duration {requested} exceeds the maximum allowed {max}")]
+    DurationTooLong {
+        max: chrono::Duration,
+        requested: chrono::Duration,
+    },
+
+    #[error("reservation {0} cannot be archived unless it is cancelled")]
+    NotArchivable(String),
+
+    #[error("reservations cannot be merged: {0}")]
+    NotMergeable(String),
+
+    #[error(
+        "invalid status transition: {} -> {}",
+        .from.as_str_name(),
+        .to.as_str_name()
+    )]
+    InvalidStatusTransition {
+        from: crate::ReservationStatus,
+        to: crate::ReservationStatus,
+    },
+
+    #[error("user {user_id} already holds {limit} active reservations")]
+    QuotaExceeded { user_id: String, limit: u32 },
+
+    #[error("reservation {id} was modified concurrently; reload and retry")]
+    VersionConflict { id: String },
+
+    #[error("unknown error")]
+    Unknown,
+}
+
+impl From<sqlx::Error> for Error {
+    fn from(e: sqlx::Error) -> Self {
+        match e {
+            sqlx::Error::Database(e) => {
+                let err: &PgDatabaseError = e.downcast_ref();
+                match (err.code(), err.constraint()) {
+                    // the legacy overlap exclusion constraint (pre-capacity
+                    // schemas): a booking conflict, never a generic database
+                    // error
+                    ("23P01", Some("reservations_conflict")) => Error::ConflictReservation(
+                        err.detail().unwrap_or_default().parse().unwrap(),
+                    ),
+                    ("23505", constraint) => {
+                        Error::UniqueViolation(constraint.unwrap_or_default().to_string())
+                    }
+                    _ => Error::DbError(sqlx::Error::Database(e)),
+                }
+            }
+            sqlx::Error::RowNotFound => Error::NotFound,
+            // the database itself is unreachable or the pool gave up; the
+            // call may well succeed on retry, unlike other database errors
+            e @ (sqlx::Error::Io(_)
+            | sqlx::Error::Tls(_)
+            | sqlx::Error::PoolTimedOut
+            | sqlx::Error::PoolClosed) => Error::ConnectionFailed(e),
+            _ => Error::DbError(e),
+        }
+    }
+}
+
+impl From<Error> for tonic::Status {
+    fn from(e: Error) -> Self {
+        match e {
+            Error::ConflictReservation(_) | Error::UniqueViolation(_) => {
+                tonic::Status::already_exists(e.to_string())
+            }
+            Error::NotFound => tonic::Status::not_found(e.to_string()),
+            Error::InvalidTime
+            | Error::InvalidUserId(_)
+            | Error::InvalidResourceId(_)
+            | Error::InvalidReservationId(_)
+            | Error::InvalidField(_)
+            | Error::InvalidStatus(_)
+            | Error::DurationTooLong { .. } => tonic::Status::invalid_argument(e.to_string()),
+            Error::NotArchivable(_)
+            | Error::NotMergeable(_)
+            | Error::InvalidStatusTransition { .. } => {
+                tonic::Status::failed_precondition(e.to_string())
+            }
+            Error::QuotaExceeded { .. } => tonic::Status::resource_exhausted(e.to_string()),
+            // ABORTED is the canonical code for optimistic-lock failures: the
+            // client should reload and retry the whole edit
+            Error::VersionConflict { .. } => tonic::Status::aborted(e.to_string()),
+            Error::ConnectionFailed(_) => tonic::Status::unavailable(e.to_string()),
+            Error::DbError(_) | Error::Unknown => tonic::Status::internal(e.to_string()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sqlx_errors_should_map_to_domain_errors() {
+        assert!(matches!(
+            Error::from(sqlx::Error::RowNotFound),
+            Error::NotFound
+        ));
+        assert!(matches!(
+            Error::from(sqlx::Error::PoolTimedOut),
+            Error::ConnectionFailed(sqlx::Error::PoolTimedOut)
+        ));
+        assert!(matches!(
+            Error::from(sqlx::Error::PoolClosed),
+            Error::ConnectionFailed(sqlx::Error::PoolClosed)
+        ));
+        let io = sqlx::Error::Io(std::io::Error::new(
+            std::io::ErrorKind::ConnectionRefused,
+            "refused",
+        ));
+        assert!(matches!(Error::from(io), Error::ConnectionFailed(_)));
+        // anything else stays a database error
+        assert!(matches!(
+            Error::from(sqlx::Error::WorkerCrashed),
+            Error::DbError(_)
+        ));
+    }
+
+    #[test]
+    fn domain_errors_should_map_to_the_right_status_codes() {
+        use tonic::Code;
+        let cases = [
+            (Error::NotFound, Code::NotFound),
+            (Error::InvalidTime, Code::InvalidArgument),
+            (
+                Error::UniqueViolation("idempotency".to_string()),
+                Code::AlreadyExists,
+            ),
+            (
+                Error::ConnectionFailed(sqlx::Error::PoolTimedOut),
+                Code::Unavailable,
+            ),
+            (
+                Error::VersionConflict {
+                    id: "a".to_string(),
+                },
+                Code::Aborted,
+            ),
+            (Error::Unknown, Code::Internal),
+        ];
+        for (error, code) in cases {
+            assert_eq!(tonic::Status::from(error).code(), code);
+        }
+    }
+}
@@ -1,4 +1,6 @@
+// This file is @generated by prost-build.
 /// Core reservation object. contains all the information for a reservation.
+#[derive(serde::Serialize, serde::Deserialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct Reservation {
@@ -16,13 +18,53 @@ pub struct Reservation {
     pub resource_id: ::prost::alloc::string::String,
     /// Start time for the reservation.
     #[prost(message, optional, tag = "5")]
+    #[serde(with = "crate::utils::timestamp_serde")]
     pub start: ::core::option::Option<::prost_types::Timestamp>,
     /// End time for the reservation.
     #[prost(message, optional, tag = "6")]
+    #[serde(with = "crate::utils::timestamp_serde")]
     pub end: ::core::option::Option<::prost_types::Timestamp>,
     /// Extra note.
     #[prost(string, tag = "7")]
     pub note: ::prost::alloc::string::String,
+    /// Version for optimistic concurrency, starts at 1 and increments on
+    /// every mutation.
+    #[prost(int32, tag = "8")]
+    pub version: i32,
+    /// When the reservation was created; set by the server, never changes.
+    #[prost(message, optional, tag = "9")]
+    #[serde(with = "crate::utils::timestamp_serde")]
+    pub created_at: ::core::option::Option<::prost_types::Timestamp>,
+    /// When the reservation last changed; set by the server on every mutation.
+    #[prost(message, optional, tag = "10")]
+    #[serde(with = "crate::utils::timestamp_serde")]
+    pub updated_at: ::core::option::Option<::prost_types::Timestamp>,
+}
+/// Reservation fields for creating a new reservation, the id is generated by the server.
+#[derive(serde::Serialize, serde::Deserialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ReservationInfo {
+    /// User id who makes the reservation.
+    #[prost(string, tag = "1")]
+    pub user_id: ::prost::alloc::string::String,
+    /// Resource to reserve.
+    #[prost(string, tag = "2")]
+    pub resource_id: ::prost::alloc::string::String,
+    /// Start time for the reservation.
+    #[prost(message, optional, tag = "3")]
+    #[serde(with = "crate::utils::timestamp_serde")]
+    pub start: ::core::option::Option<::prost_types::Timestamp>,
+    /// End time for the reservation.
+    #[prost(message, optional, tag = "4")]
+    #[serde(with = "crate::utils::timestamp_serde")]
+    pub end: ::core::option::Option<::prost_types::Timestamp>,
+    /// Reservation status, PENDING if unset.
+    #[prost(enumeration = "ReservationStatus", tag = "5")]
+    pub status: i32,
+    /// Extra note.
+    #[prost(string, tag = "6")]
+    pub note: ::prost::alloc::string::String,
 }
 /// To make a reservation, send a ReserveRequest with Reservation object(id should be empty).
 #[allow(clippy::derive_partial_eq_without_eq)]
@@ -30,6 +72,10 @@ pub struct Reservation {
 pub struct ReserveRequest {
     #[prost(message, optional, tag = "1")]
     pub reservation: ::core::option::Option<Reservation>,
+    /// (option)client-chosen key that makes retries safe: a repeated key
+    /// returns the reservation created the first time instead of a duplicate.
+    #[prost(string, tag = "2")]
+    pub idempotency_key: ::prost::alloc::string::String,
 }
 /// After a reservation is made, the ReserveResponse will be returned.
 #[allow(clippy::derive_partial_eq_without_eq)]
@@ -38,6 +84,65 @@ pub struct ReserveResponse {
     #[prost(message, optional, tag = "1")]
     pub reservation: ::core::option::Option<Reservation>,
 }
+/// Rule describing how a base reservation expands into a series.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RecurrenceRule {
+    #[prost(enumeration = "RecurrenceFrequency", tag = "1")]
+    pub freq: i32,
+    /// Repeat every `interval` days/weeks, 1 if unset.
+    #[prost(uint32, tag = "2")]
+    pub interval: u32,
+    /// IANA time zone the series is anchored in, UTC if empty. Occurrences
+    /// keep their local wall-clock time across DST transitions.
+    #[prost(string, tag = "5")]
+    pub time_zone: ::prost::alloc::string::String,
+    #[prost(oneof = "recurrence_rule::CountOrUntil", tags = "3, 4")]
+    pub count_or_until: ::core::option::Option<recurrence_rule::CountOrUntil>,
+}
+/// Nested message and enum types in `RecurrenceRule`.
+pub mod recurrence_rule {
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum CountOrUntil {
+        /// Total number of occurrences, including the base one.
+        #[prost(uint32, tag = "3")]
+        Count(u32),
+        /// Expand occurrences whose start is not after this time.
+        #[prost(message, tag = "4")]
+        Until(::prost_types::Timestamp),
+    }
+}
+/// To book a whole recurring series in one transaction, send a ReserveRecurringRequest.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ReserveRecurringRequest {
+    #[prost(message, optional, tag = "1")]
+    pub reservation: ::core::option::Option<ReservationInfo>,
+    #[prost(message, optional, tag = "2")]
+    pub rule: ::core::option::Option<RecurrenceRule>,
+}
+/// After the whole series is booked, the ReserveRecurringResponse will be returned.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ReserveRecurringResponse {
+    #[prost(message, repeated, tag = "1")]
+    pub reservations: ::prost::alloc::vec::Vec<Reservation>,
+}
+/// To reserve several reservations all-or-nothing, send a BatchReserveRequest.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct BatchReserveRequest {
+    #[prost(message, repeated, tag = "1")]
+    pub reservations: ::prost::alloc::vec::Vec<ReservationInfo>,
+}
+/// After all reservations are made, the BatchReserveResponse will be returned.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct BatchReserveResponse {
+    #[prost(message, repeated, tag = "1")]
+    pub reservations: ::prost::alloc::vec::Vec<Reservation>,
+}
 /// To change a reservation status from pending to confirmed, send a ConfirmRequest object with reservation id.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -52,14 +157,34 @@ pub struct ConfirmResponse {
     #[prost(message, optional, tag = "1")]
     pub reservation: ::core::option::Option<Reservation>,
 }
-/// To update a reservation note, send a UpdateRequest object.
+/// To update a reservation, send an UpdateRequest with the new values and an
+/// update_mask naming the fields to apply. Valid paths are "note",
+/// "resource_id", "start" and "end"; "id" and "user_id" are immutable.
+/// An empty mask leaves the reservation unchanged.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct UpdateRequest {
     #[prost(string, tag = "1")]
     pub id: ::prost::alloc::string::String,
+    /// New note for the reservation.
     #[prost(string, tag = "2")]
     pub note: ::prost::alloc::string::String,
+    /// New resource for the reservation.
+    #[prost(string, tag = "3")]
+    pub resource_id: ::prost::alloc::string::String,
+    /// New start time for the reservation.
+    #[prost(message, optional, tag = "4")]
+    pub start: ::core::option::Option<::prost_types::Timestamp>,
+    /// New end time for the reservation.
+    #[prost(message, optional, tag = "5")]
+    pub end: ::core::option::Option<::prost_types::Timestamp>,
+    /// Which of the fields above to apply.
+    #[prost(message, optional, tag = "6")]
+    pub update_mask: ::core::option::Option<::prost_types::FieldMask>,
+    /// Reject the update unless the reservation still has this version;
+    /// 0 skips the check.
+    #[prost(int32, tag = "7")]
+    pub expected_version: i32,
 }
 /// After the reservation node is updated, the UpdateResponse will be returned.
 #[allow(clippy::derive_partial_eq_without_eq)]
@@ -68,20 +193,99 @@ pub struct UpdateResponse {
     #[prost(message, optional, tag = "1")]
     pub reservation: ::core::option::Option<Reservation>,
 }
-/// To cancel a reservation, send a CancelRequest object, the reservation will be deleted.
+/// To cancel a reservation, send a CancelRequest object, the reservation is
+/// kept for audit with status CANCELLED.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CancelRequest {
     #[prost(string, tag = "1")]
     pub id: ::prost::alloc::string::String,
 }
-/// After the reservation is deleted, the CancelResponse will be returned.
+/// After the reservation is cancelled, the CancelResponse will be returned.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CancelResponse {
     #[prost(message, optional, tag = "1")]
     pub reservation: ::core::option::Option<Reservation>,
 }
+/// To move a cancelled reservation out of the default query results while
+/// keeping the row, send an ArchiveRequest object.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ArchiveRequest {
+    #[prost(string, tag = "1")]
+    pub id: ::prost::alloc::string::String,
+}
+/// After the reservation is archived, the ArchiveResponse will be returned.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ArchiveResponse {
+    #[prost(message, optional, tag = "1")]
+    pub reservation: ::core::option::Option<Reservation>,
+}
+/// To move a reservation to a new time window, send a RescheduleRequest object.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RescheduleRequest {
+    #[prost(string, tag = "1")]
+    pub id: ::prost::alloc::string::String,
+    /// New start time for the reservation.
+    #[prost(message, optional, tag = "2")]
+    pub start: ::core::option::Option<::prost_types::Timestamp>,
+    /// New end time for the reservation.
+    #[prost(message, optional, tag = "3")]
+    pub end: ::core::option::Option<::prost_types::Timestamp>,
+    /// Reject the reschedule unless the reservation still has this version;
+    /// 0 skips the check.
+    #[prost(int32, tag = "4")]
+    pub expected_version: i32,
+}
+/// After the reservation is rescheduled, the RescheduleResponse will be returned.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RescheduleResponse {
+    #[prost(message, optional, tag = "1")]
+    pub reservation: ::core::option::Option<Reservation>,
+}
+/// Cut a reservation in two at a point in time, e.g. when a room changes
+/// owner mid-stay. Both halves keep the resource, user, status and note.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SplitRequest {
+    #[prost(string, tag = "1")]
+    pub id: ::prost::alloc::string::String,
+    /// Where to cut; must lie strictly inside the reservation's window.
+    #[prost(message, optional, tag = "2")]
+    pub at: ::core::option::Option<::prost_types::Timestamp>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SplitResponse {
+    /// The shortened original, now ending at the split point.
+    #[prost(message, optional, tag = "1")]
+    pub first: ::core::option::Option<Reservation>,
+    /// The new reservation covering the split point to the original end.
+    #[prost(message, optional, tag = "2")]
+    pub second: ::core::option::Option<Reservation>,
+}
+/// Replace two back-to-back (or overlapping) reservations for the same
+/// resource and user with one spanning both. A gap between them, or
+/// differing resource/user, rejects the merge.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct MergeRequest {
+    #[prost(string, tag = "1")]
+    pub id_a: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub id_b: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct MergeResponse {
+    /// The merged reservation; status and note come from the earlier half.
+    #[prost(message, optional, tag = "1")]
+    pub reservation: ::core::option::Option<Reservation>,
+}
 /// To get a reservation, send a GetRequest object with reservation id.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -96,10 +300,56 @@ pub struct GetResponse {
     #[prost(message, optional, tag = "1")]
     pub reservation: ::core::option::Option<Reservation>,
 }
+/// Query criteria for reservations.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ReservationQuery {
+    /// (option)filter by resource id.
+    #[prost(string, tag = "1")]
+    pub resource_id: ::prost::alloc::string::String,
+    /// (option)filter by user id.
+    #[prost(string, tag = "2")]
+    pub user_id: ::prost::alloc::string::String,
+    /// (option)use status to filter reservations. if UNKNOWN, return all reservations
+    #[prost(enumeration = "ReservationStatus", tag = "3")]
+    pub status: i32,
+    /// (option)filter by start time, if 0, use Infinity for start time.
+    #[prost(message, optional, tag = "4")]
+    pub start: ::core::option::Option<::prost_types::Timestamp>,
+    /// (option)filter by end time, if 0, use Infinity for end time.
+    #[prost(message, optional, tag = "5")]
+    pub end: ::core::option::Option<::prost_types::Timestamp>,
+    /// Also return archived reservations, defaults to false.
+    #[prost(bool, tag = "6")]
+    pub include_archived: bool,
+    /// Leave out maintenance blocks; they are included by default.
+    #[prost(bool, tag = "7")]
+    pub exclude_blocked: bool,
+    /// (option)case-insensitive substring match on the note; % and _ in the
+    /// input are taken literally.
+    #[prost(string, tag = "8")]
+    pub note_contains: ::prost::alloc::string::String,
+    /// (option)match any of these statuses; empty means all. Takes precedence
+    /// over the single status field when non-empty.
+    #[prost(enumeration = "ReservationStatus", repeated, tag = "9")]
+    pub statuses: ::prost::alloc::vec::Vec<i32>,
+    /// (option)match any of these resource ids; empty means all. Takes
+    /// precedence over the single resource_id field when non-empty.
+    #[prost(string, repeated, tag = "10")]
+    pub resource_ids: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+}
 /// To query reservations, send a QueryRequest object.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct QueryRequest {
+    #[prost(message, optional, tag = "1")]
+    pub query: ::core::option::Option<ReservationQuery>,
+}
+/// Query criteria plus keyset pagination. Results are ordered by (start, id)
+/// and a page is requested by passing the id of the last reservation seen.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ReservationFilter {
     /// (option)filter by resource id.
     #[prost(string, tag = "1")]
     pub resource_id: ::prost::alloc::string::String,
@@ -115,11 +365,257 @@ pub struct QueryRequest {
     /// (option)filter by end time, if 0, use Infinity for end time.
     #[prost(message, optional, tag = "5")]
     pub end: ::core::option::Option<::prost_types::Timestamp>,
+    /// Id of the last reservation of the previous page, empty for the first page.
+    #[prost(string, tag = "6")]
+    pub cursor: ::prost::alloc::string::String,
+    /// Page size, defaults to 100 and is capped at 500.
+    #[prost(int32, tag = "7")]
+    pub page_size: i32,
+    /// Sort column, start time if UNKNOWN.
+    #[prost(enumeration = "SortField", tag = "8")]
+    pub order_by: i32,
+    /// Sort direction, ascending by default.
+    #[prost(bool, tag = "9")]
+    pub desc: bool,
+    /// Also return archived reservations, defaults to false.
+    #[prost(bool, tag = "10")]
+    pub include_archived: bool,
+    /// Leave out maintenance blocks; they are included by default.
+    #[prost(bool, tag = "11")]
+    pub exclude_blocked: bool,
+    /// (option)case-insensitive substring match on the note; % and _ in the
+    /// input are taken literally.
+    #[prost(string, tag = "12")]
+    pub note_contains: ::prost::alloc::string::String,
+    /// (option)match any of these statuses; empty means all. Takes precedence
+    /// over the single status field when non-empty.
+    #[prost(enumeration = "ReservationStatus", repeated, tag = "13")]
+    pub statuses: ::prost::alloc::vec::Vec<i32>,
+    /// (option)match any of these resource ids; empty means all. Takes
+    /// precedence over the single resource_id field when non-empty.
+    #[prost(string, repeated, tag = "14")]
+    pub resource_ids: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    /// (option)only reservations created after this instant.
+    #[prost(message, optional, tag = "15")]
+    pub created_after: ::core::option::Option<::prost_types::Timestamp>,
+    /// (option)only reservations last changed after this instant.
+    #[prost(message, optional, tag = "16")]
+    pub updated_after: ::core::option::Option<::prost_types::Timestamp>,
+}
+/// To fetch one page of reservations, send a FilterRequest object.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct FilterRequest {
+    #[prost(message, optional, tag = "1")]
+    pub filter: ::core::option::Option<ReservationFilter>,
+}
+/// One page of reservations; next_cursor is empty when there are no more pages.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct FilterResponse {
+    #[prost(message, repeated, tag = "1")]
+    pub reservations: ::prost::alloc::vec::Vec<Reservation>,
+    #[prost(string, tag = "2")]
+    pub next_cursor: ::prost::alloc::string::String,
+}
+/// Block out a maintenance window on a resource; the blocked span takes part
+/// in the same conflict checking as normal reservations.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct BlockRequest {
+    #[prost(string, tag = "1")]
+    pub resource_id: ::prost::alloc::string::String,
+    #[prost(message, optional, tag = "2")]
+    pub start: ::core::option::Option<::prost_types::Timestamp>,
+    #[prost(message, optional, tag = "3")]
+    pub end: ::core::option::Option<::prost_types::Timestamp>,
+    /// Why the resource is blocked, e.g. "cleaning".
+    #[prost(string, tag = "4")]
+    pub note: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct BlockResponse {
+    #[prost(message, optional, tag = "1")]
+    pub reservation: ::core::option::Option<Reservation>,
+}
+/// Ask whether a window could be reserved, without inserting anything.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CheckAvailabilityRequest {
+    #[prost(string, tag = "1")]
+    pub resource_id: ::prost::alloc::string::String,
+    #[prost(message, optional, tag = "2")]
+    pub start: ::core::option::Option<::prost_types::Timestamp>,
+    #[prost(message, optional, tag = "3")]
+    pub end: ::core::option::Option<::prost_types::Timestamp>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CheckAvailabilityResponse {
+    /// True when no active reservation overlaps the window; a reservation made
+    /// right after can still conflict if someone else books in between.
+    #[prost(bool, tag = "1")]
+    pub available: bool,
+    /// Ids of the overlapping reservations, when not available.
+    #[prost(string, repeated, tag = "2")]
+    pub conflicting_ids: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+}
+/// Count reservations matching the query criteria without fetching rows.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CountRequest {
+    /// Criteria to count by; unset counts all reservations.
+    #[prost(message, optional, tag = "1")]
+    pub query: ::core::option::Option<ReservationQuery>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CountResponse {
+    #[prost(int64, tag = "1")]
+    pub count: i64,
+}
+/// Bucket reservations per resource for a queried window.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct AggregateRequest {
+    /// Criteria to aggregate over; unset aggregates all reservations.
+    #[prost(message, optional, tag = "1")]
+    pub query: ::core::option::Option<ReservationQuery>,
+    /// Return only the busiest n resources; 0 returns all.
+    #[prost(int32, tag = "2")]
+    pub top_n: i32,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ResourceCount {
+    #[prost(string, tag = "1")]
+    pub resource_id: ::prost::alloc::string::String,
+    #[prost(int64, tag = "2")]
+    pub count: i64,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct AggregateResponse {
+    /// Ordered by count descending.
+    #[prost(message, repeated, tag = "1")]
+    pub resources: ::prost::alloc::vec::Vec<ResourceCount>,
+}
+/// Day/week view of a resource: the range is cut into fixed-size slots and
+/// each slot reports whether anything overlaps it.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CalendarRequest {
+    #[prost(string, tag = "1")]
+    pub resource_id: ::prost::alloc::string::String,
+    /// Start of the calendar range.
+    #[prost(message, optional, tag = "2")]
+    pub start: ::core::option::Option<::prost_types::Timestamp>,
+    /// End of the calendar range; only whole slots are returned, so a trailing
+    /// partial slot is dropped.
+    #[prost(message, optional, tag = "3")]
+    pub end: ::core::option::Option<::prost_types::Timestamp>,
+    /// Slot width in seconds, e.g. 1800 for half-hour slots.
+    #[prost(int64, tag = "4")]
+    pub slot_seconds: i64,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CalendarSlot {
+    #[prost(message, optional, tag = "1")]
+    pub start: ::core::option::Option<::prost_types::Timestamp>,
+    #[prost(message, optional, tag = "2")]
+    pub end: ::core::option::Option<::prost_types::Timestamp>,
+    /// BLOCKED wins over RESERVED when both touch the slot.
+    #[prost(enumeration = "SlotStatus", tag = "3")]
+    pub status: i32,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CalendarResponse {
+    #[prost(message, repeated, tag = "1")]
+    pub slots: ::prost::alloc::vec::Vec<CalendarSlot>,
+}
+/// One message of the client-streamed bulk import.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct BulkImportRequest {
+    #[prost(message, optional, tag = "1")]
+    pub reservation: ::core::option::Option<ReservationInfo>,
+    /// Read from the first message only: skip the batch-wide capacity check
+    /// that otherwise runs at commit. Without it, overlapping historical rows
+    /// must be sent with status CANCELLED, which the check exempts.
+    #[prost(bool, tag = "2")]
+    pub allow_overlaps: bool,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct BulkImportResponse {
+    /// Rows loaded by COPY.
+    #[prost(int64, tag = "1")]
+    pub inserted: i64,
+    /// Rows dropped because they failed validation.
+    #[prost(int64, tag = "2")]
+    pub skipped: i64,
+    /// The first validation failure, empty when nothing was skipped.
+    #[prost(string, tag = "3")]
+    pub first_error: ::prost::alloc::string::String,
+}
+/// Admin-only bulk delete of every reservation matching the query.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct DeleteByQueryRequest {
+    /// Criteria to delete by; must narrow the result set unless
+    /// confirm_delete_all is set.
+    #[prost(message, optional, tag = "1")]
+    pub query: ::core::option::Option<ReservationQuery>,
+    /// Explicit opt-in for a query that would delete every reservation.
+    #[prost(bool, tag = "2")]
+    pub confirm_delete_all: bool,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct DeleteByQueryResponse {
+    /// Number of reservations removed.
+    #[prost(uint64, tag = "1")]
+    pub deleted: u64,
+}
+/// Proxy-friendly long poll over the same change log as watch, for clients
+/// that cannot hold a streaming RPC open.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PollChangesRequest {
+    /// Return changes with change_id greater than this. 0 (the default)
+    /// starts from now.
+    #[prost(int64, tag = "1")]
+    pub resume_from: i64,
+    /// How long to wait for a change when none are pending, in seconds.
+    /// 0 returns immediately; the server caps overly long waits.
+    #[prost(int64, tag = "2")]
+    pub wait_seconds: i64,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PollChangesResponse {
+    /// Changes in change_id order; empty when the wait timed out. The server
+    /// caps the batch size, so keep polling with next_resume_from until a
+    /// poll comes back empty to drain a long backlog.
+    #[prost(message, repeated, tag = "1")]
+    pub changes: ::prost::alloc::vec::Vec<WatchResponse>,
+    /// Feed back as resume_from in the next poll; unchanged from the request
+    /// when no changes arrived.
+    #[prost(int64, tag = "2")]
+    pub next_resume_from: i64,
 }
 /// Client can watch to reservation changes by sending a WatchRequest.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
-pub struct WatchRequest {}
+pub struct WatchRequest {
+    /// Replay persisted changes with change_id greater than this before
+    /// switching to live changes. 0 (the default) starts from now.
+    #[prost(int64, tag = "1")]
+    pub resume_from: i64,
+}
 /// Server can send WatchResponse to client in stream.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -127,6 +623,13 @@ pub struct WatchResponse {
     /// Change type for the reservation.
     #[prost(enumeration = "ReservationChangeType", tag = "1")]
     pub change_type: i32,
+    /// Monotonically increasing change id; feed it back as resume_from to
+    /// resume the stream without missing changes.
+    #[prost(int64, tag = "2")]
+    pub change_id: i64,
+    /// The reservation after the change; unset when the row has been deleted.
+    #[prost(message, optional, tag = "3")]
+    pub reservation: ::core::option::Option<Reservation>,
 }
 /// Reservation status for a given time period.
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
@@ -136,6 +639,7 @@ pub enum ReservationStatus {
     Pending = 1,
     Confirmed = 2,
     Blocked = 3,
+    Cancelled = 4,
 }
 impl ReservationStatus {
     /// String value of the enum field names used in the ProtoBuf definition.
@@ -148,6 +652,7 @@ impl ReservationStatus {
             ReservationStatus::Pending => "RESERVATION_STATUS_PENDING",
             ReservationStatus::Confirmed => "RESERVATION_STATUS_CONFIRMED",
             ReservationStatus::Blocked => "RESERVATION_STATUS_BLOCKED",
+            ReservationStatus::Cancelled => "RESERVATION_STATUS_CANCELLED",
         }
     }
     /// Creates an enum from field names used in the ProtoBuf definition.
@@ -157,6 +662,7 @@ impl ReservationStatus {
             "RESERVATION_STATUS_PENDING" => Some(Self::Pending),
             "RESERVATION_STATUS_CONFIRMED" => Some(Self::Confirmed),
             "RESERVATION_STATUS_BLOCKED" => Some(Self::Blocked),
+            "RESERVATION_STATUS_CANCELLED" => Some(Self::Cancelled),
             _ => None,
         }
     }
@@ -194,6 +700,105 @@ impl ReservationChangeType {
         }
     }
 }
+/// How often a recurring reservation repeats.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum RecurrenceFrequency {
+    Unknown = 0,
+    Daily = 1,
+    Weekly = 2,
+}
+impl RecurrenceFrequency {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            RecurrenceFrequency::Unknown => "RECURRENCE_FREQUENCY_UNKNOWN",
+            RecurrenceFrequency::Daily => "RECURRENCE_FREQUENCY_DAILY",
+            RecurrenceFrequency::Weekly => "RECURRENCE_FREQUENCY_WEEKLY",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "RECURRENCE_FREQUENCY_UNKNOWN" => Some(Self::Unknown),
+            "RECURRENCE_FREQUENCY_DAILY" => Some(Self::Daily),
+            "RECURRENCE_FREQUENCY_WEEKLY" => Some(Self::Weekly),
+            _ => None,
+        }
+    }
+}
+/// Sort column for filter results, id is always kept as a tiebreaker.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum SortField {
+    Unknown = 0,
+    Id = 1,
+    Start = 2,
+    ResourceId = 3,
+    Status = 4,
+}
+impl SortField {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            SortField::Unknown => "SORT_FIELD_UNKNOWN",
+            SortField::Id => "SORT_FIELD_ID",
+            SortField::Start => "SORT_FIELD_START",
+            SortField::ResourceId => "SORT_FIELD_RESOURCE_ID",
+            SortField::Status => "SORT_FIELD_STATUS",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "SORT_FIELD_UNKNOWN" => Some(Self::Unknown),
+            "SORT_FIELD_ID" => Some(Self::Id),
+            "SORT_FIELD_START" => Some(Self::Start),
+            "SORT_FIELD_RESOURCE_ID" => Some(Self::ResourceId),
+            "SORT_FIELD_STATUS" => Some(Self::Status),
+            _ => None,
+        }
+    }
+}
+/// Occupancy of one calendar slot.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum SlotStatus {
+    Unknown = 0,
+    Free = 1,
+    Reserved = 2,
+    Blocked = 3,
+}
+impl SlotStatus {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            SlotStatus::Unknown => "SLOT_STATUS_UNKNOWN",
+            SlotStatus::Free => "SLOT_STATUS_FREE",
+            SlotStatus::Reserved => "SLOT_STATUS_RESERVED",
+            SlotStatus::Blocked => "SLOT_STATUS_BLOCKED",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "SLOT_STATUS_UNKNOWN" => Some(Self::Unknown),
+            "SLOT_STATUS_FREE" => Some(Self::Free),
+            "SLOT_STATUS_RESERVED" => Some(Self::Reserved),
+            "SLOT_STATUS_BLOCKED" => Some(Self::Blocked),
+            _ => None,
+        }
+    }
+}
 /// Generated client implementations.
 pub mod reservation_service_client {
     #![allow(unused_variables, dead_code, missing_docs, clippy::let_unit_value)]
@@ -298,11 +903,12 @@ pub mod reservation_service_client {
                 .insert(GrpcMethod::new("reservation.ReservationService", "reserve"));
             self.inner.unary(req, path, codec).await
         }
-        /// Confirm a pending reservation, if reservation is not pending, do nothing.
-        pub async fn confirm(
+        /// Make several reservations in one transaction, all succeed or all fail.
+        pub async fn batch_reserve(
             &mut self,
-            request: impl tonic::IntoRequest<super::ConfirmRequest>,
-        ) -> std::result::Result<tonic::Response<super::ConfirmResponse>, tonic::Status> {
+            request: impl tonic::IntoRequest<super::BatchReserveRequest>,
+        ) -> std::result::Result<tonic::Response<super::BatchReserveResponse>, tonic::Status>
+        {
             self.inner.ready().await.map_err(|e| {
                 tonic::Status::new(
                     tonic::Code::Unknown,
@@ -310,14 +916,59 @@ pub mod reservation_service_client {
                 )
             })?;
             let codec = tonic::codec::ProstCodec::default();
-            let path =
+            let path = http::uri::PathAndQuery::from_static(
+                "/reservation.ReservationService/batch_reserve",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut().insert(GrpcMethod::new(
+                "reservation.ReservationService",
+                "batch_reserve",
+            ));
+            self.inner.unary(req, path, codec).await
+        }
+        /// Expand a recurrence rule and book the whole series in one transaction.
+        pub async fn reserve_recurring(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ReserveRecurringRequest>,
+        ) -> std::result::Result<tonic::Response<super::ReserveRecurringResponse>, tonic::Status>
+        {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/reservation.ReservationService/reserve_recurring",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut().insert(GrpcMethod::new(
+                "reservation.ReservationService",
+                "reserve_recurring",
+            ));
+            self.inner.unary(req, path, codec).await
+        }
+        /// Confirm a pending reservation; any other status is an invalid transition.
+        pub async fn confirm(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ConfirmRequest>,
+        ) -> std::result::Result<tonic::Response<super::ConfirmResponse>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path =
                 http::uri::PathAndQuery::from_static("/reservation.ReservationService/confirm");
             let mut req = request.into_request();
             req.extensions_mut()
                 .insert(GrpcMethod::new("reservation.ReservationService", "confirm"));
             self.inner.unary(req, path, codec).await
         }
-        /// Update the reservation note.
+        /// Update the fields selected by the update_mask.
         pub async fn update(
             &mut self,
             request: impl tonic::IntoRequest<super::UpdateRequest>,
@@ -355,6 +1006,85 @@ pub mod reservation_service_client {
                 .insert(GrpcMethod::new("reservation.ReservationService", "cancel"));
             self.inner.unary(req, path, codec).await
         }
+        /// Archive a cancelled reservation, hiding it from default query results.
+        pub async fn archive(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ArchiveRequest>,
+        ) -> std::result::Result<tonic::Response<super::ArchiveResponse>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path =
+                http::uri::PathAndQuery::from_static("/reservation.ReservationService/archive");
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("reservation.ReservationService", "archive"));
+            self.inner.unary(req, path, codec).await
+        }
+        /// Reschedule a reservation to a new time window, the status is unchanged.
+        pub async fn reschedule(
+            &mut self,
+            request: impl tonic::IntoRequest<super::RescheduleRequest>,
+        ) -> std::result::Result<tonic::Response<super::RescheduleResponse>, tonic::Status>
+        {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path =
+                http::uri::PathAndQuery::from_static("/reservation.ReservationService/reschedule");
+            let mut req = request.into_request();
+            req.extensions_mut().insert(GrpcMethod::new(
+                "reservation.ReservationService",
+                "reschedule",
+            ));
+            self.inner.unary(req, path, codec).await
+        }
+        /// Split a reservation in two at a point inside its window.
+        pub async fn split(
+            &mut self,
+            request: impl tonic::IntoRequest<super::SplitRequest>,
+        ) -> std::result::Result<tonic::Response<super::SplitResponse>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path =
+                http::uri::PathAndQuery::from_static("/reservation.ReservationService/split");
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("reservation.ReservationService", "split"));
+            self.inner.unary(req, path, codec).await
+        }
+        /// Merge two adjacent or overlapping reservations into one.
+        pub async fn merge(
+            &mut self,
+            request: impl tonic::IntoRequest<super::MergeRequest>,
+        ) -> std::result::Result<tonic::Response<super::MergeResponse>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path =
+                http::uri::PathAndQuery::from_static("/reservation.ReservationService/merge");
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("reservation.ReservationService", "merge"));
+            self.inner.unary(req, path, codec).await
+        }
         /// Get a reservation by id.
         pub async fn get(
             &mut self,
@@ -374,6 +1104,7 @@ pub mod reservation_service_client {
             self.inner.unary(req, path, codec).await
         }
         /// Query reservations by resource id, user id, status, start time, end time.
+        /// Pages are fetched lazily from the database and streamed to the client.
         pub async fn query(
             &mut self,
             request: impl tonic::IntoRequest<super::QueryRequest>,
@@ -395,6 +1126,175 @@ pub mod reservation_service_client {
                 .insert(GrpcMethod::new("reservation.ReservationService", "query"));
             self.inner.server_streaming(req, path, codec).await
         }
+        /// Fetch one page of reservations matching the filter.
+        pub async fn filter(
+            &mut self,
+            request: impl tonic::IntoRequest<super::FilterRequest>,
+        ) -> std::result::Result<tonic::Response<super::FilterResponse>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path =
+                http::uri::PathAndQuery::from_static("/reservation.ReservationService/filter");
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("reservation.ReservationService", "filter"));
+            self.inner.unary(req, path, codec).await
+        }
+        /// Count reservations matching the query without materializing rows.
+        pub async fn count(
+            &mut self,
+            request: impl tonic::IntoRequest<super::CountRequest>,
+        ) -> std::result::Result<tonic::Response<super::CountResponse>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path =
+                http::uri::PathAndQuery::from_static("/reservation.ReservationService/count");
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("reservation.ReservationService", "count"));
+            self.inner.unary(req, path, codec).await
+        }
+        /// Dry-run availability check with the same overlap semantics as reserve.
+        pub async fn check_availability(
+            &mut self,
+            request: impl tonic::IntoRequest<super::CheckAvailabilityRequest>,
+        ) -> std::result::Result<tonic::Response<super::CheckAvailabilityResponse>, tonic::Status>
+        {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/reservation.ReservationService/check_availability",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut().insert(GrpcMethod::new(
+                "reservation.ReservationService",
+                "check_availability",
+            ));
+            self.inner.unary(req, path, codec).await
+        }
+        /// Block out a maintenance window so nobody can book over it.
+        pub async fn block(
+            &mut self,
+            request: impl tonic::IntoRequest<super::BlockRequest>,
+        ) -> std::result::Result<tonic::Response<super::BlockResponse>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path =
+                http::uri::PathAndQuery::from_static("/reservation.ReservationService/block");
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("reservation.ReservationService", "block"));
+            self.inner.unary(req, path, codec).await
+        }
+        /// Count reservations per resource without fetching rows.
+        pub async fn aggregate_by_resource(
+            &mut self,
+            request: impl tonic::IntoRequest<super::AggregateRequest>,
+        ) -> std::result::Result<tonic::Response<super::AggregateResponse>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/reservation.ReservationService/aggregate_by_resource",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut().insert(GrpcMethod::new(
+                "reservation.ReservationService",
+                "aggregate_by_resource",
+            ));
+            self.inner.unary(req, path, codec).await
+        }
+        /// Bucket a resource's reservations into fixed-size calendar slots.
+        pub async fn calendar(
+            &mut self,
+            request: impl tonic::IntoRequest<super::CalendarRequest>,
+        ) -> std::result::Result<tonic::Response<super::CalendarResponse>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path =
+                http::uri::PathAndQuery::from_static("/reservation.ReservationService/calendar");
+            let mut req = request.into_request();
+            req.extensions_mut().insert(GrpcMethod::new(
+                "reservation.ReservationService",
+                "calendar",
+            ));
+            self.inner.unary(req, path, codec).await
+        }
+        /// Load historical reservations in bulk over Postgres COPY.
+        pub async fn bulk_import(
+            &mut self,
+            request: impl tonic::IntoStreamingRequest<Message = super::BulkImportRequest>,
+        ) -> std::result::Result<tonic::Response<super::BulkImportResponse>, tonic::Status>
+        {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path =
+                http::uri::PathAndQuery::from_static("/reservation.ReservationService/bulk_import");
+            let mut req = request.into_streaming_request();
+            req.extensions_mut().insert(GrpcMethod::new(
+                "reservation.ReservationService",
+                "bulk_import",
+            ));
+            self.inner.client_streaming(req, path, codec).await
+        }
+        /// Admin-only: delete every reservation matching the query in one
+        /// statement and return the number removed.
+        pub async fn delete_by_query(
+            &mut self,
+            request: impl tonic::IntoRequest<super::DeleteByQueryRequest>,
+        ) -> std::result::Result<tonic::Response<super::DeleteByQueryResponse>, tonic::Status>
+        {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/reservation.ReservationService/delete_by_query",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut().insert(GrpcMethod::new(
+                "reservation.ReservationService",
+                "delete_by_query",
+            ));
+            self.inner.unary(req, path, codec).await
+        }
         /// another system could watch for reservation changes like: added/confirmed/canceled
         pub async fn watch(
             &mut self,
@@ -417,6 +1317,30 @@ pub mod reservation_service_client {
                 .insert(GrpcMethod::new("reservation.ReservationService", "watch"));
             self.inner.server_streaming(req, path, codec).await
         }
+        /// Long-poll alternative to watch for proxies that break long-lived
+        /// streams: returns pending changes, or waits up to wait_seconds for one.
+        pub async fn poll_changes(
+            &mut self,
+            request: impl tonic::IntoRequest<super::PollChangesRequest>,
+        ) -> std::result::Result<tonic::Response<super::PollChangesResponse>, tonic::Status>
+        {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/reservation.ReservationService/poll_changes",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut().insert(GrpcMethod::new(
+                "reservation.ReservationService",
+                "poll_changes",
+            ));
+            self.inner.unary(req, path, codec).await
+        }
     }
 }
 /// Generated server implementations.
@@ -431,12 +1355,22 @@ pub mod reservation_service_server {
             &self,
             request: tonic::Request<super::ReserveRequest>,
         ) -> std::result::Result<tonic::Response<super::ReserveResponse>, tonic::Status>;
-        /// Confirm a pending reservation, if reservation is not pending, do nothing.
+        /// Make several reservations in one transaction, all succeed or all fail.
+        async fn batch_reserve(
+            &self,
+            request: tonic::Request<super::BatchReserveRequest>,
+        ) -> std::result::Result<tonic::Response<super::BatchReserveResponse>, tonic::Status>;
+        /// Expand a recurrence rule and book the whole series in one transaction.
+        async fn reserve_recurring(
+            &self,
+            request: tonic::Request<super::ReserveRecurringRequest>,
+        ) -> std::result::Result<tonic::Response<super::ReserveRecurringResponse>, tonic::Status>;
+        /// Confirm a pending reservation; any other status is an invalid transition.
         async fn confirm(
             &self,
             request: tonic::Request<super::ConfirmRequest>,
         ) -> std::result::Result<tonic::Response<super::ConfirmResponse>, tonic::Status>;
-        /// Update the reservation note.
+        /// Update the fields selected by the update_mask.
         async fn update(
             &self,
             request: tonic::Request<super::UpdateRequest>,
@@ -446,6 +1380,26 @@ pub mod reservation_service_server {
             &self,
             request: tonic::Request<super::CancelRequest>,
         ) -> std::result::Result<tonic::Response<super::CancelResponse>, tonic::Status>;
+        /// Archive a cancelled reservation, hiding it from default query results.
+        async fn archive(
+            &self,
+            request: tonic::Request<super::ArchiveRequest>,
+        ) -> std::result::Result<tonic::Response<super::ArchiveResponse>, tonic::Status>;
+        /// Reschedule a reservation to a new time window, the status is unchanged.
+        async fn reschedule(
+            &self,
+            request: tonic::Request<super::RescheduleRequest>,
+        ) -> std::result::Result<tonic::Response<super::RescheduleResponse>, tonic::Status>;
+        /// Split a reservation in two at a point inside its window.
+        async fn split(
+            &self,
+            request: tonic::Request<super::SplitRequest>,
+        ) -> std::result::Result<tonic::Response<super::SplitResponse>, tonic::Status>;
+        /// Merge two adjacent or overlapping reservations into one.
+        async fn merge(
+            &self,
+            request: tonic::Request<super::MergeRequest>,
+        ) -> std::result::Result<tonic::Response<super::MergeResponse>, tonic::Status>;
         /// Get a reservation by id.
         async fn get(
             &self,
@@ -457,10 +1411,52 @@ pub mod reservation_service_server {
             > + Send
             + 'static;
         /// Query reservations by resource id, user id, status, start time, end time.
+        /// Pages are fetched lazily from the database and streamed to the client.
         async fn query(
             &self,
             request: tonic::Request<super::QueryRequest>,
         ) -> std::result::Result<tonic::Response<Self::queryStream>, tonic::Status>;
+        /// Fetch one page of reservations matching the filter.
+        async fn filter(
+            &self,
+            request: tonic::Request<super::FilterRequest>,
+        ) -> std::result::Result<tonic::Response<super::FilterResponse>, tonic::Status>;
+        /// Count reservations matching the query without materializing rows.
+        async fn count(
+            &self,
+            request: tonic::Request<super::CountRequest>,
+        ) -> std::result::Result<tonic::Response<super::CountResponse>, tonic::Status>;
+        /// Dry-run availability check with the same overlap semantics as reserve.
+        async fn check_availability(
+            &self,
+            request: tonic::Request<super::CheckAvailabilityRequest>,
+        ) -> std::result::Result<tonic::Response<super::CheckAvailabilityResponse>, tonic::Status>;
+        /// Block out a maintenance window so nobody can book over it.
+        async fn block(
+            &self,
+            request: tonic::Request<super::BlockRequest>,
+        ) -> std::result::Result<tonic::Response<super::BlockResponse>, tonic::Status>;
+        /// Count reservations per resource without fetching rows.
+        async fn aggregate_by_resource(
+            &self,
+            request: tonic::Request<super::AggregateRequest>,
+        ) -> std::result::Result<tonic::Response<super::AggregateResponse>, tonic::Status>;
+        /// Bucket a resource's reservations into fixed-size calendar slots.
+        async fn calendar(
+            &self,
+            request: tonic::Request<super::CalendarRequest>,
+        ) -> std::result::Result<tonic::Response<super::CalendarResponse>, tonic::Status>;
+        /// Load historical reservations in bulk over Postgres COPY.
+        async fn bulk_import(
+            &self,
+            request: tonic::Request<tonic::Streaming<super::BulkImportRequest>>,
+        ) -> std::result::Result<tonic::Response<super::BulkImportResponse>, tonic::Status>;
+        /// Admin-only: delete every reservation matching the query in one
+        /// statement and return the number removed.
+        async fn delete_by_query(
+            &self,
+            request: tonic::Request<super::DeleteByQueryRequest>,
+        ) -> std::result::Result<tonic::Response<super::DeleteByQueryResponse>, tonic::Status>;
         /// Server streaming response type for the watch method.
         type watchStream: tonic::codegen::tokio_stream::Stream<
                 Item = std::result::Result<super::WatchResponse, tonic::Status>,
@@ -471,6 +1467,12 @@ pub mod reservation_service_server {
             &self,
             request: tonic::Request<super::WatchRequest>,
         ) -> std::result::Result<tonic::Response<Self::watchStream>, tonic::Status>;
+        /// Long-poll alternative to watch for proxies that break long-lived
+        /// streams: returns pending changes, or waits up to wait_seconds for one.
+        async fn poll_changes(
+            &self,
+            request: tonic::Request<super::PollChangesRequest>,
+        ) -> std::result::Result<tonic::Response<super::PollChangesResponse>, tonic::Status>;
     }
     /// ReservationService provides reservation operations.
     #[derive(Debug)]
@@ -589,19 +1591,22 @@ pub mod reservation_service_server {
                     };
                     Box::pin(fut)
                 }
-                "/reservation.ReservationService/confirm" => {
+                "/reservation.ReservationService/batch_reserve" => {
                     #[allow(non_camel_case_types)]
-                    struct confirmSvc<T: ReservationService>(pub Arc<T>);
-                    impl<T: ReservationService> tonic::server::UnaryService<super::ConfirmRequest> for confirmSvc<T> {
-                        type Response = super::ConfirmResponse;
+                    struct batch_reserveSvc<T: ReservationService>(pub Arc<T>);
+                    impl<T: ReservationService>
+                        tonic::server::UnaryService<super::BatchReserveRequest>
+                        for batch_reserveSvc<T>
+                    {
+                        type Response = super::BatchReserveResponse;
                         type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::ConfirmRequest>,
+                            request: tonic::Request<super::BatchReserveRequest>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as ReservationService>::confirm(&inner, request).await
+                                <T as ReservationService>::batch_reserve(&inner, request).await
                             };
                             Box::pin(fut)
                         }
@@ -613,7 +1618,7 @@ pub mod reservation_service_server {
                     let inner = self.inner.clone();
                     let fut = async move {
                         let inner = inner.0;
-                        let method = confirmSvc(inner);
+                        let method = batch_reserveSvc(inner);
                         let codec = tonic::codec::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -629,19 +1634,22 @@ pub mod reservation_service_server {
                     };
                     Box::pin(fut)
                 }
-                "/reservation.ReservationService/update" => {
+                "/reservation.ReservationService/reserve_recurring" => {
                     #[allow(non_camel_case_types)]
-                    struct updateSvc<T: ReservationService>(pub Arc<T>);
-                    impl<T: ReservationService> tonic::server::UnaryService<super::UpdateRequest> for updateSvc<T> {
-                        type Response = super::UpdateResponse;
+                    struct reserve_recurringSvc<T: ReservationService>(pub Arc<T>);
+                    impl<T: ReservationService>
+                        tonic::server::UnaryService<super::ReserveRecurringRequest>
+                        for reserve_recurringSvc<T>
+                    {
+                        type Response = super::ReserveRecurringResponse;
                         type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::UpdateRequest>,
+                            request: tonic::Request<super::ReserveRecurringRequest>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as ReservationService>::update(&inner, request).await
+                                <T as ReservationService>::reserve_recurring(&inner, request).await
                             };
                             Box::pin(fut)
                         }
@@ -653,7 +1661,7 @@ pub mod reservation_service_server {
                     let inner = self.inner.clone();
                     let fut = async move {
                         let inner = inner.0;
-                        let method = updateSvc(inner);
+                        let method = reserve_recurringSvc(inner);
                         let codec = tonic::codec::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -669,19 +1677,19 @@ pub mod reservation_service_server {
                     };
                     Box::pin(fut)
                 }
-                "/reservation.ReservationService/cancel" => {
+                "/reservation.ReservationService/confirm" => {
                     #[allow(non_camel_case_types)]
-                    struct cancelSvc<T: ReservationService>(pub Arc<T>);
-                    impl<T: ReservationService> tonic::server::UnaryService<super::CancelRequest> for cancelSvc<T> {
-                        type Response = super::CancelResponse;
+                    struct confirmSvc<T: ReservationService>(pub Arc<T>);
+                    impl<T: ReservationService> tonic::server::UnaryService<super::ConfirmRequest> for confirmSvc<T> {
+                        type Response = super::ConfirmResponse;
                         type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::CancelRequest>,
+                            request: tonic::Request<super::ConfirmRequest>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as ReservationService>::cancel(&inner, request).await
+                                <T as ReservationService>::confirm(&inner, request).await
                             };
                             Box::pin(fut)
                         }
@@ -693,7 +1701,7 @@ pub mod reservation_service_server {
                     let inner = self.inner.clone();
                     let fut = async move {
                         let inner = inner.0;
-                        let method = cancelSvc(inner);
+                        let method = confirmSvc(inner);
                         let codec = tonic::codec::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -709,19 +1717,19 @@ pub mod reservation_service_server {
                     };
                     Box::pin(fut)
                 }
-                "/reservation.ReservationService/get" => {
+                "/reservation.ReservationService/update" => {
                     #[allow(non_camel_case_types)]
-                    struct getSvc<T: ReservationService>(pub Arc<T>);
-                    impl<T: ReservationService> tonic::server::UnaryService<super::GetRequest> for getSvc<T> {
-                        type Response = super::GetResponse;
+                    struct updateSvc<T: ReservationService>(pub Arc<T>);
+                    impl<T: ReservationService> tonic::server::UnaryService<super::UpdateRequest> for updateSvc<T> {
+                        type Response = super::UpdateResponse;
                         type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::GetRequest>,
+                            request: tonic::Request<super::UpdateRequest>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as ReservationService>::get(&inner, request).await
+                                <T as ReservationService>::update(&inner, request).await
                             };
                             Box::pin(fut)
                         }
@@ -733,7 +1741,7 @@ pub mod reservation_service_server {
                     let inner = self.inner.clone();
                     let fut = async move {
                         let inner = inner.0;
-                        let method = getSvc(inner);
+                        let method = updateSvc(inner);
                         let codec = tonic::codec::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -749,23 +1757,345 @@ pub mod reservation_service_server {
                     };
                     Box::pin(fut)
                 }
-                "/reservation.ReservationService/query" => {
+                "/reservation.ReservationService/cancel" => {
                     #[allow(non_camel_case_types)]
-                    struct querySvc<T: ReservationService>(pub Arc<T>);
-                    impl<T: ReservationService>
-                        tonic::server::ServerStreamingService<super::QueryRequest> for querySvc<T>
-                    {
-                        type Response = super::Reservation;
+                    struct cancelSvc<T: ReservationService>(pub Arc<T>);
+                    impl<T: ReservationService> tonic::server::UnaryService<super::CancelRequest> for cancelSvc<T> {
+                        type Response = super::CancelResponse;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::CancelRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as ReservationService>::cancel(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = cancelSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/reservation.ReservationService/archive" => {
+                    #[allow(non_camel_case_types)]
+                    struct archiveSvc<T: ReservationService>(pub Arc<T>);
+                    impl<T: ReservationService> tonic::server::UnaryService<super::ArchiveRequest> for archiveSvc<T> {
+                        type Response = super::ArchiveResponse;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ArchiveRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as ReservationService>::archive(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = archiveSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/reservation.ReservationService/reschedule" => {
+                    #[allow(non_camel_case_types)]
+                    struct rescheduleSvc<T: ReservationService>(pub Arc<T>);
+                    impl<T: ReservationService>
+                        tonic::server::UnaryService<super::RescheduleRequest> for rescheduleSvc<T>
+                    {
+                        type Response = super::RescheduleResponse;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::RescheduleRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as ReservationService>::reschedule(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = rescheduleSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/reservation.ReservationService/split" => {
+                    #[allow(non_camel_case_types)]
+                    struct splitSvc<T: ReservationService>(pub Arc<T>);
+                    impl<T: ReservationService> tonic::server::UnaryService<super::SplitRequest> for splitSvc<T> {
+                        type Response = super::SplitResponse;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::SplitRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as ReservationService>::split(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = splitSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/reservation.ReservationService/merge" => {
+                    #[allow(non_camel_case_types)]
+                    struct mergeSvc<T: ReservationService>(pub Arc<T>);
+                    impl<T: ReservationService> tonic::server::UnaryService<super::MergeRequest> for mergeSvc<T> {
+                        type Response = super::MergeResponse;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::MergeRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as ReservationService>::merge(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = mergeSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/reservation.ReservationService/get" => {
+                    #[allow(non_camel_case_types)]
+                    struct getSvc<T: ReservationService>(pub Arc<T>);
+                    impl<T: ReservationService> tonic::server::UnaryService<super::GetRequest> for getSvc<T> {
+                        type Response = super::GetResponse;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::GetRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as ReservationService>::get(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = getSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/reservation.ReservationService/query" => {
+                    #[allow(non_camel_case_types)]
+                    struct querySvc<T: ReservationService>(pub Arc<T>);
+                    impl<T: ReservationService>
+                        tonic::server::ServerStreamingService<super::QueryRequest> for querySvc<T>
+                    {
+                        type Response = super::Reservation;
                         type ResponseStream = T::queryStream;
                         type Future =
                             BoxFuture<tonic::Response<Self::ResponseStream>, tonic::Status>;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::QueryRequest>,
+                            request: tonic::Request<super::QueryRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as ReservationService>::query(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = querySvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.server_streaming(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/reservation.ReservationService/filter" => {
+                    #[allow(non_camel_case_types)]
+                    struct filterSvc<T: ReservationService>(pub Arc<T>);
+                    impl<T: ReservationService> tonic::server::UnaryService<super::FilterRequest> for filterSvc<T> {
+                        type Response = super::FilterResponse;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::FilterRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as ReservationService>::filter(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = filterSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/reservation.ReservationService/count" => {
+                    #[allow(non_camel_case_types)]
+                    struct countSvc<T: ReservationService>(pub Arc<T>);
+                    impl<T: ReservationService> tonic::server::UnaryService<super::CountRequest> for countSvc<T> {
+                        type Response = super::CountResponse;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::CountRequest>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as ReservationService>::query(&inner, request).await
+                                <T as ReservationService>::count(&inner, request).await
                             };
                             Box::pin(fut)
                         }
@@ -777,7 +2107,7 @@ pub mod reservation_service_server {
                     let inner = self.inner.clone();
                     let fut = async move {
                         let inner = inner.0;
-                        let method = querySvc(inner);
+                        let method = countSvc(inner);
                         let codec = tonic::codec::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -788,7 +2118,259 @@ pub mod reservation_service_server {
                                 max_decoding_message_size,
                                 max_encoding_message_size,
                             );
-                        let res = grpc.server_streaming(method, req).await;
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/reservation.ReservationService/check_availability" => {
+                    #[allow(non_camel_case_types)]
+                    struct check_availabilitySvc<T: ReservationService>(pub Arc<T>);
+                    impl<T: ReservationService>
+                        tonic::server::UnaryService<super::CheckAvailabilityRequest>
+                        for check_availabilitySvc<T>
+                    {
+                        type Response = super::CheckAvailabilityResponse;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::CheckAvailabilityRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as ReservationService>::check_availability(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = check_availabilitySvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/reservation.ReservationService/block" => {
+                    #[allow(non_camel_case_types)]
+                    struct blockSvc<T: ReservationService>(pub Arc<T>);
+                    impl<T: ReservationService> tonic::server::UnaryService<super::BlockRequest> for blockSvc<T> {
+                        type Response = super::BlockResponse;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::BlockRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as ReservationService>::block(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = blockSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/reservation.ReservationService/aggregate_by_resource" => {
+                    #[allow(non_camel_case_types)]
+                    struct aggregate_by_resourceSvc<T: ReservationService>(pub Arc<T>);
+                    impl<T: ReservationService> tonic::server::UnaryService<super::AggregateRequest>
+                        for aggregate_by_resourceSvc<T>
+                    {
+                        type Response = super::AggregateResponse;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::AggregateRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as ReservationService>::aggregate_by_resource(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = aggregate_by_resourceSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/reservation.ReservationService/calendar" => {
+                    #[allow(non_camel_case_types)]
+                    struct calendarSvc<T: ReservationService>(pub Arc<T>);
+                    impl<T: ReservationService> tonic::server::UnaryService<super::CalendarRequest> for calendarSvc<T> {
+                        type Response = super::CalendarResponse;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::CalendarRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as ReservationService>::calendar(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = calendarSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/reservation.ReservationService/bulk_import" => {
+                    #[allow(non_camel_case_types)]
+                    struct bulk_importSvc<T: ReservationService>(pub Arc<T>);
+                    impl<T: ReservationService>
+                        tonic::server::ClientStreamingService<super::BulkImportRequest>
+                        for bulk_importSvc<T>
+                    {
+                        type Response = super::BulkImportResponse;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<tonic::Streaming<super::BulkImportRequest>>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as ReservationService>::bulk_import(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = bulk_importSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.client_streaming(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/reservation.ReservationService/delete_by_query" => {
+                    #[allow(non_camel_case_types)]
+                    struct delete_by_querySvc<T: ReservationService>(pub Arc<T>);
+                    impl<T: ReservationService>
+                        tonic::server::UnaryService<super::DeleteByQueryRequest>
+                        for delete_by_querySvc<T>
+                    {
+                        type Response = super::DeleteByQueryResponse;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::DeleteByQueryRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as ReservationService>::delete_by_query(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = delete_by_querySvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
                         Ok(res)
                     };
                     Box::pin(fut)
@@ -837,6 +2419,49 @@ pub mod reservation_service_server {
                     };
                     Box::pin(fut)
                 }
+                "/reservation.ReservationService/poll_changes" => {
+                    #[allow(non_camel_case_types)]
+                    struct poll_changesSvc<T: ReservationService>(pub Arc<T>);
+                    impl<T: ReservationService>
+                        tonic::server::UnaryService<super::PollChangesRequest>
+                        for poll_changesSvc<T>
+                    {
+                        type Response = super::PollChangesResponse;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::PollChangesRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as ReservationService>::poll_changes(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = poll_changesSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
                 _ => Box::pin(async move {
                     Ok(http::Response::builder()
                         .status(200)
@@ -0,0 +1,255 @@
+mod query_builder;
+mod recurrence;
+mod reservation;
+mod reservation_filter;
+mod reservation_info;
+mod reservation_status;
+mod update_request;
+
+use std::ops::Bound;
+
+use chrono::{DateTime, Duration, Utc};
+use prost_types::Timestamp;
+use sqlx::postgres::types::PgRange;
+use uuid::Uuid;
+
+pub use query_builder::{ReservationFilterBuilder, ReservationQueryBuilder};
+pub use recurrence::{expand_recurrence, MAX_OCCURRENCES};
+pub use reservation_status::{can_transition, statuses_to_db, RsvpStatus};
+pub use update_request::UpdateField;
+
+use crate::{convert_to_utc_time, Error};
+
+/// Validation of request messages before they hit the database, so malformed
+/// input surfaces as a precise `Error` instead of a database failure.
+pub trait Validate {
+    fn validate(&self) -> Result<(), Error>;
+}
+
+/// Parse a reservation id into the uuid used by the database.
+pub fn parse_reservation_id(id: &str) -> Result<Uuid, Error> {
+    id.parse()
+        .map_err(|_| Error::InvalidReservationId(id.to_string()))
+}
+
+/// Validate a (start, end) pair and build the half-open `[start, end)` range
+/// stored in the `tstzrange` column.
+pub fn validate_range(
+    start: Option<&Timestamp>,
+    end: Option<&Timestamp>,
+) -> Result<PgRange<DateTime<Utc>>, Error> {
+    let (start, end) = match (start, end) {
+        (Some(start), Some(end)) => (convert_to_utc_time(start), convert_to_utc_time(end)),
+        _ => return Err(Error::InvalidTime),
+    };
+    if start >= end {
+        return Err(Error::InvalidTime);
+    }
+    Ok((start..end).into())
+}
+
+/// Build the range used for overlap queries. Unlike [`validate_range`],
+/// either bound may be missing: a missing start yields `(,end)`, a missing
+/// end yields `[start,)`, and both missing yields the fully unbounded range.
+pub fn query_range(
+    start: Option<&Timestamp>,
+    end: Option<&Timestamp>,
+) -> Result<PgRange<DateTime<Utc>>, Error> {
+    let start = start.map(convert_to_utc_time);
+    let end = end.map(convert_to_utc_time);
+    if let (Some(start), Some(end)) = (start, end) {
+        if start >= end {
+            return Err(Error::InvalidTime);
+        }
+    }
+    Ok(PgRange {
+        start: start.map_or(Bound::Unbounded, Bound::Included),
+        end: end.map_or(Bound::Unbounded, Bound::Excluded),
+    })
+}
+
+/// Whether two half-open `[start, end)` windows overlap, with exactly the
+/// semantics of Postgres `tstzrange && tstzrange` as used by the store's
+/// capacity check. The boundary is half-open on purpose: a window
+/// ending at 12:00 and one starting at 12:00 touch but do NOT overlap, so
+/// back-to-back reservations are fine.
+pub fn ranges_overlap(
+    a_start: DateTime<Utc>,
+    a_end: DateTime<Utc>,
+    b_start: DateTime<Utc>,
+    b_end: DateTime<Utc>,
+) -> bool {
+    a_start < b_end && b_start < a_end
+}
+
+/// Escape `%`, `_` and `\` so user input matches literally inside a
+/// LIKE/ILIKE pattern.
+pub fn escape_like(input: &str) -> String {
+    let mut out = String::with_capacity(input.len());
+    for c in input.chars() {
+        if matches!(c, '%' | '_' | '\\') {
+            out.push('\\');
+        }
+        out.push(c);
+    }
+    out
+}
+
+/// Validate an identifier destined for `search_path` or `CREATE SCHEMA`,
+/// so a configured tenant schema can be spliced into SQL without quoting or
+/// injection concerns: lowercase ascii, digits and underscores only, not
+/// starting with a digit, at most 63 bytes (the Postgres identifier limit).
+pub fn validate_schema_name(name: &str) -> Result<(), Error> {
+    let mut chars = name.chars();
+    let valid = matches!(chars.next(), Some(c) if c.is_ascii_lowercase() || c == '_')
+        && chars.all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_')
+        && name.len() <= 63;
+    if valid {
+        Ok(())
+    } else {
+        Err(Error::InvalidField(format!("schema: {name}")))
+    }
+}
+
+/// Enforce the optional maximum-duration policy on a time window, before any
+/// SQL runs. `None` means unlimited.
+pub fn validate_max_duration(
+    start: Option<&Timestamp>,
+    end: Option<&Timestamp>,
+    max: Option<Duration>,
+) -> Result<(), Error> {
+    let Some(max) = max else { return Ok(()) };
+    let (start, end) = match (start, end) {
+        (Some(start), Some(end)) => (convert_to_utc_time(start), convert_to_utc_time(end)),
+        _ => return Err(Error::InvalidTime),
+    };
+    let requested = end - start;
+    if requested > max {
+        return Err(Error::DurationTooLong { max, requested });
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::convert_to_timestamp;
+    use chrono::TimeZone;
+
+    #[test]
+    fn validate_range_should_reject_missing_or_inverted_bounds() {
+        let start = convert_to_timestamp(&Utc.with_ymd_and_hms(2024, 3, 26, 10, 0, 0).unwrap());
+        let end = convert_to_timestamp(&Utc.with_ymd_and_hms(2024, 3, 26, 12, 0, 0).unwrap());
+
+        assert!(validate_range(Some(&start), Some(&end)).is_ok());
+        assert!(matches!(
+            validate_range(None, Some(&end)),
+            Err(Error::InvalidTime)
+        ));
+        assert!(matches!(
+            validate_range(Some(&start), None),
+            Err(Error::InvalidTime)
+        ));
+        assert!(matches!(
+            validate_range(Some(&end), Some(&start)),
+            Err(Error::InvalidTime)
+        ));
+        assert!(matches!(
+            validate_range(Some(&start), Some(&start)),
+            Err(Error::InvalidTime)
+        ));
+    }
+
+    #[test]
+    fn query_range_should_allow_each_bound_combination() {
+        let start = convert_to_timestamp(&Utc.with_ymd_and_hms(2024, 3, 26, 10, 0, 0).unwrap());
+        let end = convert_to_timestamp(&Utc.with_ymd_and_hms(2024, 3, 26, 12, 0, 0).unwrap());
+
+        // both bounded: same half-open shape as validate_range
+        let range = query_range(Some(&start), Some(&end)).unwrap();
+        assert!(matches!(range.start, Bound::Included(_)));
+        assert!(matches!(range.end, Bound::Excluded(_)));
+
+        // everything after start
+        let range = query_range(Some(&start), None).unwrap();
+        assert!(matches!(range.start, Bound::Included(_)));
+        assert!(matches!(range.end, Bound::Unbounded));
+
+        // everything before end
+        let range = query_range(None, Some(&end)).unwrap();
+        assert!(matches!(range.start, Bound::Unbounded));
+        assert!(matches!(range.end, Bound::Excluded(_)));
+
+        // fully unbounded: overlaps every reservation
+        let range = query_range(None, None).unwrap();
+        assert!(matches!(range.start, Bound::Unbounded));
+        assert!(matches!(range.end, Bound::Unbounded));
+
+        // an inverted bounded pair is still rejected
+        assert!(matches!(
+            query_range(Some(&end), Some(&start)),
+            Err(Error::InvalidTime)
+        ));
+    }
+
+    #[test]
+    fn max_duration_should_only_reject_longer_windows() {
+        let start = convert_to_timestamp(&Utc.with_ymd_and_hms(2024, 3, 26, 10, 0, 0).unwrap());
+        let end = convert_to_timestamp(&Utc.with_ymd_and_hms(2024, 3, 26, 12, 0, 0).unwrap());
+
+        assert!(validate_max_duration(Some(&start), Some(&end), None).is_ok());
+        assert!(validate_max_duration(Some(&start), Some(&end), Some(Duration::hours(2))).is_ok());
+        assert!(matches!(
+            validate_max_duration(Some(&start), Some(&end), Some(Duration::hours(1))),
+            Err(Error::DurationTooLong { .. })
+        ));
+    }
+
+    #[test]
+    fn ranges_overlap_should_use_half_open_semantics() {
+        let t = |h| Utc.with_ymd_and_hms(2024, 3, 26, h, 0, 0).unwrap();
+
+        // plain overlap, containment and identity
+        assert!(ranges_overlap(t(10), t(12), t(11), t(13)));
+        assert!(ranges_overlap(t(10), t(14), t(11), t(12)));
+        assert!(ranges_overlap(t(10), t(12), t(10), t(12)));
+
+        // back-to-back windows touch at the seam but do not overlap,
+        // in either order
+        assert!(!ranges_overlap(t(10), t(12), t(12), t(14)));
+        assert!(!ranges_overlap(t(12), t(14), t(10), t(12)));
+
+        // fully disjoint
+        assert!(!ranges_overlap(t(8), t(9), t(12), t(14)));
+    }
+
+    #[test]
+    fn escape_like_should_neutralize_pattern_metacharacters() {
+        assert_eq!(escape_like("VIP"), "VIP");
+        assert_eq!(escape_like("100%"), r"100\%");
+        assert_eq!(escape_like("a_b"), r"a\_b");
+        assert_eq!(escape_like(r"a\b"), r"a\\b");
+    }
+
+    #[test]
+    fn parse_reservation_id_should_reject_non_uuid() {
+        assert!(matches!(
+            parse_reservation_id("not-a-uuid"),
+            Err(Error::InvalidReservationId(_))
+        ));
+        assert!(parse_reservation_id("0a8d5422-2e4f-4b8a-9a4e-0d9c5e3b8c1d").is_ok());
+    }
+
+    #[test]
+    fn schema_names_should_be_plain_identifiers() {
+        for good in ["rsvp", "tenant_a", "_private", "t2"] {
+            assert!(validate_schema_name(good).is_ok(), "{good}");
+        }
+        for bad in ["", "2fast", "Tenant", "a-b", "a.b", "a;DROP TABLE x", "a b"] {
+            assert!(
+                matches!(validate_schema_name(bad), Err(Error::InvalidField(_))),
+                "{bad}"
+            );
+        }
+    }
+}
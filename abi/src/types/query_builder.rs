@@ -0,0 +1,331 @@
+//! Fluent builders for the query and filter messages, so Rust callers get
+//! one place that enforces invariants (like `start < end`) before a request
+//! leaves the process. The raw prost structs stay public for anyone who
+//! prefers to build messages by hand.
+
+use chrono::{DateTime, Utc};
+
+use crate::{
+    convert_to_timestamp, Error, ReservationFilter, ReservationQuery, ReservationStatus, SortField,
+};
+
+/// Builds a [`ReservationQuery`] step by step; every method is optional and
+/// an empty builder yields the match-everything query.
+///
+/// ```
+/// use abi::{ReservationQueryBuilder, ReservationStatus};
+/// use chrono::{TimeZone, Utc};
+///
+/// let query = ReservationQueryBuilder::default()
+///     .resource("room-101")
+///     .user("alice")
+///     .during(
+///         Utc.with_ymd_and_hms(2024, 4, 1, 10, 0, 0).unwrap(),
+///         Utc.with_ymd_and_hms(2024, 4, 1, 12, 0, 0).unwrap(),
+///     )
+///     .status(ReservationStatus::Confirmed)
+///     .build()
+///     .unwrap();
+/// assert_eq!(query.resource_id, "room-101");
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct ReservationQueryBuilder {
+    query: ReservationQuery,
+}
+
+impl ReservationQueryBuilder {
+    /// Match a single resource; for several, see [`Self::resources`].
+    pub fn resource(mut self, resource_id: impl Into<String>) -> Self {
+        self.query.resource_id = resource_id.into();
+        self
+    }
+
+    /// Match any of these resources; takes precedence over
+    /// [`Self::resource`] when non-empty.
+    pub fn resources<I, S>(mut self, resource_ids: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.query.resource_ids = resource_ids.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Match a single user.
+    pub fn user(mut self, user_id: impl Into<String>) -> Self {
+        self.query.user_id = user_id.into();
+        self
+    }
+
+    /// Match reservations overlapping `[start, end)`. `build` rejects a
+    /// window where start is not before end.
+    pub fn during(mut self, start: DateTime<Utc>, end: DateTime<Utc>) -> Self {
+        self.query.start = Some(convert_to_timestamp(&start));
+        self.query.end = Some(convert_to_timestamp(&end));
+        self
+    }
+
+    /// Match reservations overlapping everything from `start` on.
+    pub fn starting_at(mut self, start: DateTime<Utc>) -> Self {
+        self.query.start = Some(convert_to_timestamp(&start));
+        self
+    }
+
+    /// Match reservations overlapping everything up to `end`.
+    pub fn ending_at(mut self, end: DateTime<Utc>) -> Self {
+        self.query.end = Some(convert_to_timestamp(&end));
+        self
+    }
+
+    /// Match a single status; for several, see [`Self::statuses`].
+    pub fn status(mut self, status: ReservationStatus) -> Self {
+        self.query.status = status as i32;
+        self
+    }
+
+    /// Match any of these statuses; takes precedence over [`Self::status`]
+    /// when non-empty.
+    pub fn statuses<I>(mut self, statuses: I) -> Self
+    where
+        I: IntoIterator<Item = ReservationStatus>,
+    {
+        self.query.statuses = statuses.into_iter().map(|s| s as i32).collect();
+        self
+    }
+
+    /// Case-insensitive substring match on the note.
+    pub fn note_contains(mut self, needle: impl Into<String>) -> Self {
+        self.query.note_contains = needle.into();
+        self
+    }
+
+    /// Also return archived reservations.
+    pub fn include_archived(mut self) -> Self {
+        self.query.include_archived = true;
+        self
+    }
+
+    /// Leave out maintenance blocks.
+    pub fn exclude_blocked(mut self) -> Self {
+        self.query.exclude_blocked = true;
+        self
+    }
+
+    /// Validate the accumulated criteria and hand back the query message.
+    pub fn build(self) -> Result<ReservationQuery, Error> {
+        validate_window(self.query.start.as_ref(), self.query.end.as_ref())?;
+        Ok(self.query)
+    }
+}
+
+/// Builds a [`ReservationFilter`], the paged variant of the query; same
+/// criteria methods plus paging and ordering.
+#[derive(Debug, Clone, Default)]
+pub struct ReservationFilterBuilder {
+    filter: ReservationFilter,
+}
+
+impl ReservationFilterBuilder {
+    /// Match a single resource; for several, see [`Self::resources`].
+    pub fn resource(mut self, resource_id: impl Into<String>) -> Self {
+        self.filter.resource_id = resource_id.into();
+        self
+    }
+
+    /// Match any of these resources; takes precedence over
+    /// [`Self::resource`] when non-empty.
+    pub fn resources<I, S>(mut self, resource_ids: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.filter.resource_ids = resource_ids.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Match a single user.
+    pub fn user(mut self, user_id: impl Into<String>) -> Self {
+        self.filter.user_id = user_id.into();
+        self
+    }
+
+    /// Match reservations overlapping `[start, end)`. `build` rejects a
+    /// window where start is not before end.
+    pub fn during(mut self, start: DateTime<Utc>, end: DateTime<Utc>) -> Self {
+        self.filter.start = Some(convert_to_timestamp(&start));
+        self.filter.end = Some(convert_to_timestamp(&end));
+        self
+    }
+
+    /// Match a single status; for several, see [`Self::statuses`].
+    pub fn status(mut self, status: ReservationStatus) -> Self {
+        self.filter.status = status as i32;
+        self
+    }
+
+    /// Match any of these statuses; takes precedence over [`Self::status`]
+    /// when non-empty.
+    pub fn statuses<I>(mut self, statuses: I) -> Self
+    where
+        I: IntoIterator<Item = ReservationStatus>,
+    {
+        self.filter.statuses = statuses.into_iter().map(|s| s as i32).collect();
+        self
+    }
+
+    /// Case-insensitive substring match on the note.
+    pub fn note_contains(mut self, needle: impl Into<String>) -> Self {
+        self.filter.note_contains = needle.into();
+        self
+    }
+
+    /// Also return archived reservations.
+    pub fn include_archived(mut self) -> Self {
+        self.filter.include_archived = true;
+        self
+    }
+
+    /// Leave out maintenance blocks.
+    pub fn exclude_blocked(mut self) -> Self {
+        self.filter.exclude_blocked = true;
+        self
+    }
+
+    /// Only reservations created after this instant.
+    pub fn created_after(mut self, at: DateTime<Utc>) -> Self {
+        self.filter.created_after = Some(convert_to_timestamp(&at));
+        self
+    }
+
+    /// Only reservations last changed after this instant.
+    pub fn updated_after(mut self, at: DateTime<Utc>) -> Self {
+        self.filter.updated_after = Some(convert_to_timestamp(&at));
+        self
+    }
+
+    /// Resume from the id of the last reservation of the previous page.
+    pub fn cursor(mut self, cursor: impl Into<String>) -> Self {
+        self.filter.cursor = cursor.into();
+        self
+    }
+
+    /// Rows per page. `build` rejects a negative size; zero means the
+    /// server default, and oversized requests are clamped server-side.
+    pub fn page_size(mut self, page_size: i32) -> Self {
+        self.filter.page_size = page_size;
+        self
+    }
+
+    /// Sort column, start time when unset.
+    pub fn order_by(mut self, field: SortField) -> Self {
+        self.filter.order_by = field as i32;
+        self
+    }
+
+    /// Sort descending instead of ascending.
+    pub fn desc(mut self) -> Self {
+        self.filter.desc = true;
+        self
+    }
+
+    /// Validate the accumulated criteria and hand back the filter message.
+    pub fn build(self) -> Result<ReservationFilter, Error> {
+        validate_window(self.filter.start.as_ref(), self.filter.end.as_ref())?;
+        if self.filter.page_size < 0 {
+            return Err(Error::InvalidField(format!(
+                "page_size: {}",
+                self.filter.page_size
+            )));
+        }
+        Ok(self.filter)
+    }
+}
+
+/// Either bound may be missing (that side is unbounded), but a present pair
+/// must form a non-empty window.
+fn validate_window(
+    start: Option<&prost_types::Timestamp>,
+    end: Option<&prost_types::Timestamp>,
+) -> Result<(), Error> {
+    if start.is_some() || end.is_some() {
+        crate::query_range(start, end)?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    #[test]
+    fn query_builder_should_populate_every_criterion() {
+        let start = Utc.with_ymd_and_hms(2024, 4, 1, 10, 0, 0).unwrap();
+        let end = Utc.with_ymd_and_hms(2024, 4, 1, 12, 0, 0).unwrap();
+        let query = ReservationQueryBuilder::default()
+            .resource("room-101")
+            .user("alice")
+            .during(start, end)
+            .status(ReservationStatus::Confirmed)
+            .note_contains("standup")
+            .include_archived()
+            .exclude_blocked()
+            .build()
+            .unwrap();
+        assert_eq!(query.resource_id, "room-101");
+        assert_eq!(query.user_id, "alice");
+        assert_eq!(query.status, ReservationStatus::Confirmed as i32);
+        assert_eq!(query.note_contains, "standup");
+        assert!(query.include_archived);
+        assert!(query.exclude_blocked);
+        assert!(query.start.is_some() && query.end.is_some());
+    }
+
+    #[test]
+    fn builders_should_reject_an_inverted_window() {
+        let start = Utc.with_ymd_and_hms(2024, 4, 1, 12, 0, 0).unwrap();
+        let end = Utc.with_ymd_and_hms(2024, 4, 1, 10, 0, 0).unwrap();
+        assert!(matches!(
+            ReservationQueryBuilder::default()
+                .during(start, end)
+                .build(),
+            Err(Error::InvalidTime)
+        ));
+        assert!(matches!(
+            ReservationFilterBuilder::default()
+                .during(start, end)
+                .build(),
+            Err(Error::InvalidTime)
+        ));
+    }
+
+    #[test]
+    fn query_builder_should_allow_half_open_windows() {
+        let start = Utc.with_ymd_and_hms(2024, 4, 1, 10, 0, 0).unwrap();
+        let query = ReservationQueryBuilder::default()
+            .starting_at(start)
+            .build()
+            .unwrap();
+        assert!(query.start.is_some());
+        assert!(query.end.is_none());
+    }
+
+    #[test]
+    fn filter_builder_should_reject_a_negative_page_size() {
+        assert!(matches!(
+            ReservationFilterBuilder::default().page_size(-1).build(),
+            Err(Error::InvalidField(_))
+        ));
+        let filter = ReservationFilterBuilder::default()
+            .page_size(50)
+            .order_by(SortField::Id)
+            .desc()
+            .cursor("some-id")
+            .build()
+            .unwrap();
+        assert_eq!(filter.page_size, 50);
+        assert_eq!(filter.order_by, SortField::Id as i32);
+        assert!(filter.desc);
+        assert_eq!(filter.cursor, "some-id");
+    }
+}
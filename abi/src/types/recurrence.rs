@@ -0,0 +1,191 @@
+use chrono::{DateTime, Days, LocalResult, NaiveDateTime, TimeZone, Utc};
+use chrono_tz::Tz;
+
+use crate::{
+    convert_to_timestamp, convert_to_utc_time, recurrence_rule::CountOrUntil, Error,
+    RecurrenceFrequency, RecurrenceRule, ReservationInfo, Validate,
+};
+
+/// Upper bound on how many occurrences a rule may expand to, so a bad rule
+/// cannot generate an unbounded series.
+pub const MAX_OCCURRENCES: u32 = 366;
+
+/// Expand a base reservation into the concrete occurrences described by the
+/// rule, including the base itself.
+///
+/// Arithmetic is done on the local wall-clock time in the rule's time zone,
+/// so a weekly 10:00 meeting stays at 10:00 across DST transitions even
+/// though its UTC instant shifts.
+pub fn expand_recurrence(
+    base: &ReservationInfo,
+    rule: &RecurrenceRule,
+) -> Result<Vec<ReservationInfo>, Error> {
+    base.validate()?;
+    let freq = RecurrenceFrequency::try_from(rule.freq)
+        .ok()
+        .filter(|f| *f != RecurrenceFrequency::Unknown)
+        .ok_or_else(|| Error::InvalidField(format!("recurrence freq: {}", rule.freq)))?;
+    let interval = rule.interval.max(1);
+    let tz: Tz = if rule.time_zone.is_empty() {
+        Tz::UTC
+    } else {
+        rule.time_zone
+            .parse()
+            .map_err(|_| Error::InvalidField(format!("time_zone: {}", rule.time_zone)))?
+    };
+
+    let start = convert_to_utc_time(base.start.as_ref().unwrap()).with_timezone(&tz);
+    let end = convert_to_utc_time(base.end.as_ref().unwrap()).with_timezone(&tz);
+    let (count, until) = match &rule.count_or_until {
+        Some(CountOrUntil::Count(count)) => {
+            if *count > MAX_OCCURRENCES {
+                return Err(Error::InvalidField(format!("recurrence count: {count}")));
+            }
+            (*count, None)
+        }
+        Some(CountOrUntil::Until(until)) => (MAX_OCCURRENCES, Some(convert_to_utc_time(until))),
+        // without a bound the rule denotes just the base occurrence
+        None => (1, None),
+    };
+
+    let mut occurrences = Vec::new();
+    for i in 0..count {
+        let days = match freq {
+            RecurrenceFrequency::Daily => interval as u64 * i as u64,
+            RecurrenceFrequency::Weekly => interval as u64 * 7 * i as u64,
+            RecurrenceFrequency::Unknown => unreachable!(),
+        };
+        let occ_start = shift_local(&start, &tz, days)?;
+        let occ_end = shift_local(&end, &tz, days)?;
+        if let Some(until) = until {
+            if occ_start > until {
+                break;
+            }
+        }
+        let mut info = base.clone();
+        info.start = Some(convert_to_timestamp(&occ_start));
+        info.end = Some(convert_to_timestamp(&occ_end));
+        occurrences.push(info);
+    }
+    Ok(occurrences)
+}
+
+/// Add `days` to a zoned time keeping the local wall clock, resolving DST
+/// ambiguity to the earlier instant and skipping forward out of a DST gap.
+fn shift_local(base: &DateTime<Tz>, tz: &Tz, days: u64) -> Result<DateTime<Utc>, Error> {
+    let local = base
+        .naive_local()
+        .checked_add_days(Days::new(days))
+        .ok_or(Error::InvalidTime)?;
+    let resolved = match tz.from_local_datetime(&local) {
+        LocalResult::Single(dt) => dt,
+        LocalResult::Ambiguous(earliest, _) => earliest,
+        LocalResult::None => resolve_gap(tz, local)?,
+    };
+    Ok(resolved.with_timezone(&Utc))
+}
+
+/// A wall-clock time inside a spring-forward gap does not exist; move it
+/// forward an hour, matching what most calendar systems do.
+fn resolve_gap(tz: &Tz, local: NaiveDateTime) -> Result<DateTime<Tz>, Error> {
+    let shifted = local
+        .checked_add_signed(chrono::Duration::hours(1))
+        .ok_or(Error::InvalidTime)?;
+    tz.from_local_datetime(&shifted)
+        .earliest()
+        .ok_or(Error::InvalidTime)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn base(start: DateTime<Utc>, end: DateTime<Utc>) -> ReservationInfo {
+        ReservationInfo {
+            user_id: "alice".to_string(),
+            resource_id: "room-101".to_string(),
+            start: Some(convert_to_timestamp(&start)),
+            end: Some(convert_to_timestamp(&end)),
+            ..Default::default()
+        }
+    }
+
+    fn rule(freq: RecurrenceFrequency, interval: u32, count: u32, tz: &str) -> RecurrenceRule {
+        RecurrenceRule {
+            freq: freq as i32,
+            interval,
+            count_or_until: Some(CountOrUntil::Count(count)),
+            time_zone: tz.to_string(),
+        }
+    }
+
+    #[test]
+    fn weekly_rule_should_expand_to_count_occurrences() {
+        let start = Utc.with_ymd_and_hms(2024, 4, 1, 10, 0, 0).unwrap();
+        let end = Utc.with_ymd_and_hms(2024, 4, 1, 11, 0, 0).unwrap();
+        let occurrences = expand_recurrence(
+            &base(start, end),
+            &rule(RecurrenceFrequency::Weekly, 1, 3, ""),
+        )
+        .unwrap();
+
+        assert_eq!(occurrences.len(), 3);
+        for (i, occ) in occurrences.iter().enumerate() {
+            let expected = start + chrono::Duration::weeks(i as i64);
+            assert_eq!(convert_to_utc_time(occ.start.as_ref().unwrap()), expected);
+        }
+    }
+
+    #[test]
+    fn daily_rule_should_keep_wall_clock_across_dst() {
+        // America/New_York springs forward on 2024-03-10: 10:00 local is
+        // 15:00 UTC before the transition and 14:00 UTC after it
+        let start = Utc.with_ymd_and_hms(2024, 3, 9, 15, 0, 0).unwrap();
+        let end = Utc.with_ymd_and_hms(2024, 3, 9, 16, 0, 0).unwrap();
+        let occurrences = expand_recurrence(
+            &base(start, end),
+            &rule(RecurrenceFrequency::Daily, 1, 2, "America/New_York"),
+        )
+        .unwrap();
+
+        assert_eq!(occurrences.len(), 2);
+        let second = convert_to_utc_time(occurrences[1].start.as_ref().unwrap());
+        assert_eq!(second, Utc.with_ymd_and_hms(2024, 3, 10, 14, 0, 0).unwrap());
+    }
+
+    #[test]
+    fn until_should_bound_the_series() {
+        let start = Utc.with_ymd_and_hms(2024, 4, 1, 10, 0, 0).unwrap();
+        let end = Utc.with_ymd_and_hms(2024, 4, 1, 11, 0, 0).unwrap();
+        let rule = RecurrenceRule {
+            freq: RecurrenceFrequency::Daily as i32,
+            interval: 1,
+            count_or_until: Some(CountOrUntil::Until(convert_to_timestamp(
+                &Utc.with_ymd_and_hms(2024, 4, 3, 10, 0, 0).unwrap(),
+            ))),
+            time_zone: String::new(),
+        };
+        let occurrences = expand_recurrence(&base(start, end), &rule).unwrap();
+        assert_eq!(occurrences.len(), 3);
+    }
+
+    #[test]
+    fn runaway_rules_should_be_rejected() {
+        let start = Utc.with_ymd_and_hms(2024, 4, 1, 10, 0, 0).unwrap();
+        let end = Utc.with_ymd_and_hms(2024, 4, 1, 11, 0, 0).unwrap();
+        assert!(matches!(
+            expand_recurrence(
+                &base(start, end),
+                &rule(RecurrenceFrequency::Daily, 1, MAX_OCCURRENCES + 1, ""),
+            ),
+            Err(Error::InvalidField(_))
+        ));
+        assert!(matches!(
+            expand_recurrence(
+                &base(start, end),
+                &rule(RecurrenceFrequency::Unknown, 1, 2, ""),
+            ),
+            Err(Error::InvalidField(_))
+        ));
+    }
+}
@@ -0,0 +1,182 @@
+use std::ops::Bound;
+
+use chrono::{DateTime, Utc};
+use sqlx::{
+    postgres::{types::PgRange, PgRow},
+    FromRow, Row,
+};
+use uuid::Uuid;
+
+use crate::{
+    convert_to_timestamp, validate_range, Error, Reservation, ReservationStatus, ReserveRequest,
+    RsvpStatus, Validate,
+};
+
+impl Reservation {
+    /// Create a pending reservation, ready to be put into a `ReserveRequest`.
+    pub fn new_pending(
+        user_id: impl Into<String>,
+        resource_id: impl Into<String>,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+        note: impl Into<String>,
+    ) -> Self {
+        Self {
+            id: String::new(),
+            user_id: user_id.into(),
+            status: ReservationStatus::Pending as i32,
+            resource_id: resource_id.into(),
+            start: Some(convert_to_timestamp(&start)),
+            end: Some(convert_to_timestamp(&end)),
+            note: note.into(),
+            // assigned by the server on insert
+            version: 0,
+            created_at: None,
+            updated_at: None,
+        }
+    }
+
+    /// Get the timespan of the reservation as a `tstzrange` compatible
+    /// range. Fails with `Error::InvalidTime` on a missing bound or an empty
+    /// window, since a prost message can always be built with either.
+    pub fn get_timespan(&self) -> Result<PgRange<DateTime<Utc>>, Error> {
+        validate_range(self.start.as_ref(), self.end.as_ref())
+    }
+}
+
+impl Validate for Reservation {
+    fn validate(&self) -> Result<(), Error> {
+        if self.user_id.is_empty() {
+            return Err(Error::InvalidUserId(self.user_id.clone()));
+        }
+        if self.resource_id.is_empty() {
+            return Err(Error::InvalidResourceId(self.resource_id.clone()));
+        }
+        validate_range(self.start.as_ref(), self.end.as_ref())?;
+        Ok(())
+    }
+}
+
+impl Validate for ReserveRequest {
+    fn validate(&self) -> Result<(), Error> {
+        match &self.reservation {
+            Some(rsvp) => rsvp.validate(),
+            None => Err(Error::InvalidField("reservation".to_string())),
+        }
+    }
+}
+
+impl FromRow<'_, PgRow> for Reservation {
+    fn from_row(row: &PgRow) -> Result<Self, sqlx::Error> {
+        let id: Uuid = row.get("id");
+        let range: PgRange<DateTime<Utc>> = row.get("timespan");
+        let status: RsvpStatus = row.get("status");
+        let note: Option<String> = row.get("note");
+        let created_at: DateTime<Utc> = row.get("created_at");
+        let updated_at: DateTime<Utc> = row.get("updated_at");
+
+        let start = match range.start {
+            Bound::Included(v) | Bound::Excluded(v) => Some(convert_to_timestamp(&v)),
+            Bound::Unbounded => None,
+        };
+        let end = match range.end {
+            Bound::Included(v) | Bound::Excluded(v) => Some(convert_to_timestamp(&v)),
+            Bound::Unbounded => None,
+        };
+
+        Ok(Self {
+            id: id.to_string(),
+            user_id: row.get("user_id"),
+            status: ReservationStatus::from(status) as i32,
+            resource_id: row.get("resource_id"),
+            start,
+            end,
+            note: note.unwrap_or_default(),
+            version: row.get("version"),
+            created_at: Some(convert_to_timestamp(&created_at)),
+            updated_at: Some(convert_to_timestamp(&updated_at)),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn alice_reservation() -> Reservation {
+        Reservation::new_pending(
+            "alice",
+            "room-101",
+            Utc.with_ymd_and_hms(2024, 3, 26, 10, 0, 0).unwrap(),
+            Utc.with_ymd_and_hms(2024, 3, 26, 12, 0, 0).unwrap(),
+            "test",
+        )
+    }
+
+    #[test]
+    fn valid_reservation_should_pass_validation() {
+        assert!(alice_reservation().validate().is_ok());
+    }
+
+    #[test]
+    fn reservation_with_empty_user_id_should_be_rejected() {
+        let mut rsvp = alice_reservation();
+        rsvp.user_id.clear();
+        assert!(matches!(rsvp.validate(), Err(Error::InvalidUserId(_))));
+    }
+
+    #[test]
+    fn reservation_with_empty_resource_id_should_be_rejected() {
+        let mut rsvp = alice_reservation();
+        rsvp.resource_id.clear();
+        assert!(matches!(rsvp.validate(), Err(Error::InvalidResourceId(_))));
+    }
+
+    #[test]
+    fn reservation_with_bad_time_window_should_be_rejected() {
+        // missing bound
+        let mut rsvp = alice_reservation();
+        rsvp.start = None;
+        assert!(matches!(rsvp.validate(), Err(Error::InvalidTime)));
+
+        // zero-length span
+        let mut rsvp = alice_reservation();
+        rsvp.end.clone_from(&rsvp.start);
+        assert!(matches!(rsvp.validate(), Err(Error::InvalidTime)));
+
+        // start after end
+        let mut rsvp = alice_reservation();
+        std::mem::swap(&mut rsvp.start, &mut rsvp.end);
+        assert!(matches!(rsvp.validate(), Err(Error::InvalidTime)));
+    }
+
+    #[test]
+    fn reserve_request_without_reservation_should_be_rejected() {
+        let request = ReserveRequest {
+            reservation: None,
+            ..Default::default()
+        };
+        assert!(matches!(request.validate(), Err(Error::InvalidField(_))));
+        let request = ReserveRequest {
+            reservation: Some(alice_reservation()),
+            ..Default::default()
+        };
+        assert!(request.validate().is_ok());
+    }
+
+    #[test]
+    fn get_timespan_should_be_half_open() {
+        let rsvp = alice_reservation();
+        let range = rsvp.get_timespan().unwrap();
+        assert!(matches!(range.start, Bound::Included(_)));
+        assert!(matches!(range.end, Bound::Excluded(_)));
+    }
+
+    #[test]
+    fn get_timespan_should_fail_instead_of_panicking_on_a_missing_bound() {
+        let mut rsvp = alice_reservation();
+        rsvp.end = None;
+        assert!(matches!(rsvp.get_timespan(), Err(Error::InvalidTime)));
+    }
+}
@@ -0,0 +1,103 @@
+use crate::{Error, ReservationFilter, ReservationQuery, SortField};
+
+impl ReservationFilter {
+    /// Page size used when the client does not ask for one.
+    pub const DEFAULT_PAGE_SIZE: i32 = 100;
+    /// Upper bound for a single page, larger requests are clamped.
+    pub const MAX_PAGE_SIZE: i32 = 500;
+
+    /// The effective page size: default when unset, clamped to the maximum.
+    pub fn normalized_page_size(&self) -> i64 {
+        if self.page_size <= 0 {
+            Self::DEFAULT_PAGE_SIZE as i64
+        } else {
+            self.page_size.min(Self::MAX_PAGE_SIZE) as i64
+        }
+    }
+
+    /// The column to sort (and keyset-paginate) on, start time when unset.
+    /// A sort field outside the enum is rejected with `Error::InvalidField`.
+    pub fn order_by_column(&self) -> Result<&'static str, Error> {
+        match SortField::try_from(self.order_by) {
+            Ok(SortField::Unknown) | Ok(SortField::Start) => Ok("lower(timespan)"),
+            Ok(SortField::Id) => Ok("id"),
+            Ok(SortField::ResourceId) => Ok("resource_id"),
+            Ok(SortField::Status) => Ok("status"),
+            Err(_) => Err(Error::InvalidField(format!("order_by: {}", self.order_by))),
+        }
+    }
+}
+
+impl From<ReservationQuery> for ReservationFilter {
+    fn from(query: ReservationQuery) -> Self {
+        Self {
+            resource_id: query.resource_id,
+            user_id: query.user_id,
+            status: query.status,
+            start: query.start,
+            end: query.end,
+            cursor: String::new(),
+            page_size: 0,
+            order_by: SortField::Start as i32,
+            desc: false,
+            include_archived: query.include_archived,
+            exclude_blocked: query.exclude_blocked,
+            note_contains: query.note_contains,
+            statuses: query.statuses,
+            resource_ids: query.resource_ids,
+            created_after: None,
+            updated_after: None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn page_size_should_default_and_clamp() {
+        let mut filter = ReservationFilter::default();
+        assert_eq!(
+            filter.normalized_page_size(),
+            ReservationFilter::DEFAULT_PAGE_SIZE as i64
+        );
+        filter.page_size = 10;
+        assert_eq!(filter.normalized_page_size(), 10);
+        filter.page_size = 10_000;
+        assert_eq!(
+            filter.normalized_page_size(),
+            ReservationFilter::MAX_PAGE_SIZE as i64
+        );
+    }
+
+    #[test]
+    fn order_by_should_map_to_columns_and_reject_unknown_values() {
+        let mut filter = ReservationFilter::default();
+        assert_eq!(filter.order_by_column().unwrap(), "lower(timespan)");
+        filter.order_by = SortField::Id as i32;
+        assert_eq!(filter.order_by_column().unwrap(), "id");
+        filter.order_by = SortField::ResourceId as i32;
+        assert_eq!(filter.order_by_column().unwrap(), "resource_id");
+        filter.order_by = SortField::Status as i32;
+        assert_eq!(filter.order_by_column().unwrap(), "status");
+        filter.order_by = 42;
+        assert!(matches!(
+            filter.order_by_column(),
+            Err(Error::InvalidField(_))
+        ));
+    }
+
+    #[test]
+    fn filter_from_query_should_start_at_the_first_page() {
+        let query = ReservationQuery {
+            resource_id: "room-101".to_string(),
+            user_id: "alice".to_string(),
+            ..Default::default()
+        };
+        let filter = ReservationFilter::from(query);
+        assert_eq!(filter.resource_id, "room-101");
+        assert!(filter.cursor.is_empty());
+        assert_eq!(filter.page_size, 0);
+    }
+}
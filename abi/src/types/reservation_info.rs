@@ -0,0 +1,63 @@
+use crate::{validate_range, Error, Reservation, ReservationInfo, Validate};
+
+impl Validate for ReservationInfo {
+    fn validate(&self) -> Result<(), Error> {
+        if self.user_id.is_empty() {
+            return Err(Error::InvalidUserId(self.user_id.clone()));
+        }
+        if self.resource_id.is_empty() {
+            return Err(Error::InvalidResourceId(self.resource_id.clone()));
+        }
+        validate_range(self.start.as_ref(), self.end.as_ref())?;
+        Ok(())
+    }
+}
+
+impl From<ReservationInfo> for Reservation {
+    fn from(info: ReservationInfo) -> Self {
+        Self {
+            id: String::new(),
+            user_id: info.user_id,
+            status: info.status,
+            resource_id: info.resource_id,
+            start: info.start,
+            end: info.end,
+            note: info.note,
+            // assigned by the server on insert
+            version: 0,
+            created_at: None,
+            updated_at: None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::convert_to_timestamp;
+    use chrono::{TimeZone, Utc};
+
+    #[test]
+    fn reservation_info_should_validate_like_a_reservation() {
+        let info = ReservationInfo {
+            user_id: "alice".to_string(),
+            resource_id: "room-101".to_string(),
+            start: Some(convert_to_timestamp(
+                &Utc.with_ymd_and_hms(2024, 3, 26, 10, 0, 0).unwrap(),
+            )),
+            end: Some(convert_to_timestamp(
+                &Utc.with_ymd_and_hms(2024, 3, 26, 12, 0, 0).unwrap(),
+            )),
+            ..Default::default()
+        };
+        assert!(info.validate().is_ok());
+
+        let mut bad = info.clone();
+        bad.resource_id.clear();
+        assert!(matches!(bad.validate(), Err(Error::InvalidResourceId(_))));
+
+        let rsvp = Reservation::from(info);
+        assert!(rsvp.id.is_empty());
+        assert_eq!(rsvp.user_id, "alice");
+    }
+}
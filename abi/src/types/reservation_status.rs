@@ -0,0 +1,149 @@
+use crate::{Error, ReservationStatus};
+
+/// Database representation of `ReservationStatus`, mapped to the
+/// `rsvp.reservation_status` enum type.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, sqlx::Type)]
+#[sqlx(type_name = "reservation_status", rename_all = "lowercase")]
+pub enum RsvpStatus {
+    Unknown,
+    Pending,
+    Confirmed,
+    Blocked,
+    Cancelled,
+}
+
+// lets a Vec<RsvpStatus> bind as a Postgres array for ANY() predicates;
+// Postgres names the array type of an enum by prefixing an underscore
+impl sqlx::postgres::PgHasArrayType for RsvpStatus {
+    fn array_type_info() -> sqlx::postgres::PgTypeInfo {
+        sqlx::postgres::PgTypeInfo::with_name("_reservation_status")
+    }
+}
+
+/// The legal status state machine: a pending reservation can be confirmed or
+/// cancelled, a confirmed one can only be cancelled, and blocked or cancelled
+/// reservations are terminal.
+pub fn can_transition(from: ReservationStatus, to: ReservationStatus) -> bool {
+    matches!(
+        (from, to),
+        (ReservationStatus::Pending, ReservationStatus::Confirmed)
+            | (ReservationStatus::Pending, ReservationStatus::Cancelled)
+            | (ReservationStatus::Confirmed, ReservationStatus::Cancelled)
+    )
+}
+
+/// Convert the repeated statuses of a query/filter to database values for a
+/// `status = ANY($1)` predicate. Empty means "all statuses"; a value outside
+/// the enum or `UNKNOWN` (which no stored row carries) is rejected.
+pub fn statuses_to_db(statuses: &[i32]) -> Result<Vec<RsvpStatus>, Error> {
+    statuses
+        .iter()
+        .map(|&status| match ReservationStatus::try_from(status) {
+            Ok(ReservationStatus::Unknown) | Err(_) => Err(Error::InvalidStatus(status)),
+            Ok(status) => Ok(RsvpStatus::from(status)),
+        })
+        .collect()
+}
+
+impl From<RsvpStatus> for ReservationStatus {
+    fn from(status: RsvpStatus) -> Self {
+        match status {
+            RsvpStatus::Unknown => ReservationStatus::Unknown,
+            RsvpStatus::Pending => ReservationStatus::Pending,
+            RsvpStatus::Confirmed => ReservationStatus::Confirmed,
+            RsvpStatus::Blocked => ReservationStatus::Blocked,
+            RsvpStatus::Cancelled => ReservationStatus::Cancelled,
+        }
+    }
+}
+
+impl From<ReservationStatus> for RsvpStatus {
+    fn from(status: ReservationStatus) -> Self {
+        match status {
+            ReservationStatus::Unknown => RsvpStatus::Unknown,
+            ReservationStatus::Pending => RsvpStatus::Pending,
+            ReservationStatus::Confirmed => RsvpStatus::Confirmed,
+            ReservationStatus::Blocked => RsvpStatus::Blocked,
+            ReservationStatus::Cancelled => RsvpStatus::Cancelled,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn status_should_round_trip_between_pb_and_db() {
+        for status in [
+            ReservationStatus::Unknown,
+            ReservationStatus::Pending,
+            ReservationStatus::Confirmed,
+            ReservationStatus::Blocked,
+            ReservationStatus::Cancelled,
+        ] {
+            assert_eq!(ReservationStatus::from(RsvpStatus::from(status)), status);
+        }
+    }
+
+    #[test]
+    fn status_lists_should_convert_to_db_values() {
+        let two = [
+            ReservationStatus::Pending as i32,
+            ReservationStatus::Confirmed as i32,
+        ];
+        assert_eq!(
+            statuses_to_db(&two).unwrap(),
+            vec![RsvpStatus::Pending, RsvpStatus::Confirmed]
+        );
+
+        let three = [
+            ReservationStatus::Pending as i32,
+            ReservationStatus::Confirmed as i32,
+            ReservationStatus::Cancelled as i32,
+        ];
+        assert_eq!(
+            statuses_to_db(&three).unwrap(),
+            vec![
+                RsvpStatus::Pending,
+                RsvpStatus::Confirmed,
+                RsvpStatus::Cancelled
+            ]
+        );
+
+        // empty means "all statuses"
+        assert_eq!(statuses_to_db(&[]).unwrap(), vec![]);
+    }
+
+    #[test]
+    fn unknown_or_out_of_range_statuses_should_be_rejected() {
+        let unknown = [ReservationStatus::Unknown as i32];
+        assert!(matches!(
+            statuses_to_db(&unknown),
+            Err(Error::InvalidStatus(0))
+        ));
+        assert!(matches!(
+            statuses_to_db(&[42]),
+            Err(Error::InvalidStatus(42))
+        ));
+    }
+
+    #[test]
+    fn transition_table_should_only_allow_the_legal_edges() {
+        use ReservationStatus::*;
+        let all = [Unknown, Pending, Confirmed, Blocked, Cancelled];
+        for from in all {
+            for to in all {
+                let legal = matches!(
+                    (from, to),
+                    (Pending, Confirmed) | (Pending, Cancelled) | (Confirmed, Cancelled)
+                );
+                assert_eq!(
+                    can_transition(from, to),
+                    legal,
+                    "transition {from:?} -> {to:?}"
+                );
+            }
+        }
+    }
+}
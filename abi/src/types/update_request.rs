@@ -0,0 +1,97 @@
+use crate::{validate_range, Error, UpdateRequest, Validate};
+
+/// Mutable reservation fields addressable through `UpdateRequest.update_mask`.
+///
+/// `id` and `user_id` are immutable: a reservation can never change its
+/// identity or owner, only its note, resource and time window.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UpdateField {
+    Note,
+    ResourceId,
+    Start,
+    End,
+}
+
+impl UpdateRequest {
+    /// Resolve the update mask into the fields to apply.
+    ///
+    /// A missing or empty mask yields an empty list, which callers must treat
+    /// as "change nothing". Unknown or immutable paths are rejected with
+    /// `Error::InvalidField`.
+    pub fn masked_fields(&self) -> Result<Vec<UpdateField>, Error> {
+        let Some(mask) = &self.update_mask else {
+            return Ok(Vec::new());
+        };
+        let mut fields = Vec::with_capacity(mask.paths.len());
+        for path in &mask.paths {
+            let field = match path.as_str() {
+                "note" => UpdateField::Note,
+                "resource_id" => UpdateField::ResourceId,
+                "start" => UpdateField::Start,
+                "end" => UpdateField::End,
+                _ => return Err(Error::InvalidField(path.clone())),
+            };
+            if !fields.contains(&field) {
+                fields.push(field);
+            }
+        }
+        Ok(fields)
+    }
+}
+
+impl Validate for UpdateRequest {
+    fn validate(&self) -> Result<(), Error> {
+        if self.id.is_empty() {
+            return Err(Error::InvalidReservationId(self.id.clone()));
+        }
+        let fields = self.masked_fields()?;
+        // a full time window can be checked up front; a single masked bound
+        // is checked against the stored row when the update is applied
+        if fields.contains(&UpdateField::Start) && fields.contains(&UpdateField::End) {
+            validate_range(self.start.as_ref(), self.end.as_ref())?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use prost_types::FieldMask;
+
+    fn request_with_paths(paths: &[&str]) -> UpdateRequest {
+        UpdateRequest {
+            update_mask: Some(FieldMask {
+                paths: paths.iter().map(|p| p.to_string()).collect(),
+            }),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn empty_or_missing_mask_should_select_nothing() {
+        assert!(UpdateRequest::default().masked_fields().unwrap().is_empty());
+        assert!(request_with_paths(&[]).masked_fields().unwrap().is_empty());
+    }
+
+    #[test]
+    fn known_paths_should_be_resolved_in_order() {
+        let fields = request_with_paths(&["note", "start", "end", "note"])
+            .masked_fields()
+            .unwrap();
+        assert_eq!(
+            fields,
+            vec![UpdateField::Note, UpdateField::Start, UpdateField::End]
+        );
+    }
+
+    #[test]
+    fn immutable_or_unknown_paths_should_be_rejected() {
+        for path in ["id", "user_id", "status", "no_such_field"] {
+            assert!(matches!(
+                request_with_paths(&[path]).masked_fields(),
+                Err(Error::InvalidField(p)) if p == path
+            ));
+        }
+    }
+}
@@ -0,0 +1,117 @@
+//! Timestamp conversion between protobuf and chrono.
+//!
+//! Contract: a protobuf `Timestamp` is an absolute instant (seconds since the
+//! Unix epoch, no offset), and every stored value is normalized to UTC in the
+//! `timestamptz` column. Client-supplied offsets are respected on input via
+//! [`convert_offset_time`] — the offset is applied, never dropped — so
+//! "2024-03-26T18:00:00+08:00" and "2024-03-26T10:00:00Z" are the same
+//! reservation time.
+
+use chrono::{DateTime, FixedOffset, TimeZone, Utc};
+use prost_types::Timestamp;
+
+/// Convert a protobuf timestamp to a chrono `DateTime<Utc>`.
+pub fn convert_to_utc_time(ts: &Timestamp) -> DateTime<Utc> {
+    Utc.timestamp_opt(ts.seconds, ts.nanos as _).unwrap()
+}
+
+/// Convert a chrono `DateTime<Utc>` to a protobuf timestamp.
+pub fn convert_to_timestamp(dt: &DateTime<Utc>) -> Timestamp {
+    Timestamp {
+        seconds: dt.timestamp(),
+        nanos: dt.timestamp_subsec_nanos() as _,
+    }
+}
+
+/// Convert a time carrying an explicit offset to a protobuf timestamp,
+/// normalizing to UTC without changing the instant it refers to.
+pub fn convert_offset_time(dt: &DateTime<FixedOffset>) -> Timestamp {
+    convert_to_timestamp(&dt.with_timezone(&Utc))
+}
+
+/// Serde adapter for the `Option<Timestamp>` fields on generated messages,
+/// wired in through `build.rs`. Timestamps appear as RFC 3339 strings in JSON
+/// (`"2024-03-26T10:00:00+00:00"`) instead of prost's `{seconds, nanos}`.
+pub mod timestamp_serde {
+    use super::*;
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    pub fn serialize<S: Serializer>(
+        ts: &Option<Timestamp>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        ts.as_ref()
+            .map(|ts| convert_to_utc_time(ts).to_rfc3339())
+            .serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Option<Timestamp>, D::Error> {
+        let s: Option<String> = Option::deserialize(deserializer)?;
+        s.map(|s| {
+            s.parse::<DateTime<FixedOffset>>()
+                .map(|dt| convert_offset_time(&dt))
+                .map_err(serde::de::Error::custom)
+        })
+        .transpose()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn timestamp_round_trip_should_keep_the_value() {
+        let dt = Utc.with_ymd_and_hms(2024, 3, 26, 10, 30, 0).unwrap();
+        let ts = convert_to_timestamp(&dt);
+        assert_eq!(convert_to_utc_time(&ts), dt);
+    }
+
+    #[test]
+    fn offset_times_should_normalize_to_the_same_instant() {
+        // the same instant written with three different offsets
+        let utc: DateTime<FixedOffset> = "2024-03-26T10:00:00Z".parse().unwrap();
+        let shanghai: DateTime<FixedOffset> = "2024-03-26T18:00:00+08:00".parse().unwrap();
+        let new_york: DateTime<FixedOffset> = "2024-03-26T05:00:00-05:00".parse().unwrap();
+
+        let ts = convert_offset_time(&utc);
+        assert_eq!(convert_offset_time(&shanghai), ts);
+        assert_eq!(convert_offset_time(&new_york), ts);
+
+        // round trip back to UTC keeps the instant
+        assert_eq!(convert_to_utc_time(&ts), utc.with_timezone(&Utc));
+    }
+
+    #[test]
+    fn reservation_should_serialize_timestamps_as_rfc3339() {
+        let rsvp = crate::Reservation {
+            user_id: "alice".to_string(),
+            resource_id: "room-42".to_string(),
+            start: Some(convert_to_timestamp(
+                &Utc.with_ymd_and_hms(2024, 3, 26, 10, 0, 0).unwrap(),
+            )),
+            end: Some(convert_to_timestamp(
+                &Utc.with_ymd_and_hms(2024, 3, 26, 12, 0, 0).unwrap(),
+            )),
+            ..Default::default()
+        };
+        let json = serde_json::to_string(&rsvp).unwrap();
+        assert!(json.contains("\"2024-03-26T10:00:00+00:00\""));
+        let back: crate::Reservation = serde_json::from_str(&json).unwrap();
+        assert_eq!(back, rsvp);
+    }
+
+    #[test]
+    fn dst_fold_times_should_stay_distinct() {
+        // America/New_York 2024-11-03: 01:30 happens twice; the explicit
+        // offset disambiguates, and the two instants must stay an hour apart
+        let first: DateTime<FixedOffset> = "2024-11-03T01:30:00-04:00".parse().unwrap();
+        let second: DateTime<FixedOffset> = "2024-11-03T01:30:00-05:00".parse().unwrap();
+
+        let first = convert_offset_time(&first);
+        let second = convert_offset_time(&second);
+        assert_eq!(second.seconds - first.seconds, 3600);
+    }
+}
@@ -0,0 +1,253 @@
+use std::{
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+use abi::{
+    convert_to_timestamp, reservation_service_client::ReservationServiceClient, BlockRequest,
+    CancelRequest, CheckAvailabilityRequest, CheckAvailabilityResponse, ConfirmRequest,
+    CountRequest, FilterRequest, FilterResponse, GetRequest, QueryRequest, Reservation,
+    ReservationFilter, ReservationQuery, ReserveRequest, WatchRequest, WatchResponse,
+};
+use chrono::{DateTime, Utc};
+use tokio_stream::{Stream, StreamExt};
+use tonic::transport::{Certificate, Channel, ClientTlsConfig, Endpoint, Identity};
+
+use crate::Error;
+
+/// Connection settings for [`ReservationClient::connect_with`].
+#[derive(Debug, Clone, Default)]
+pub struct ClientConfig {
+    /// Per-request timeout; unset means requests never time out.
+    pub timeout: Option<Duration>,
+    /// TLS settings; unset connects in plaintext. Build from certificate
+    /// files with [`TlsFiles::load`].
+    pub tls: Option<ClientTlsConfig>,
+}
+
+/// File-based TLS settings for [`ClientConfig::tls`], mirroring the server
+/// side: the fields are paths so deployments can point at mounted secrets.
+#[derive(Debug, Clone, Default)]
+pub struct TlsFiles {
+    /// PEM encoded CA bundle used to verify the server certificate.
+    pub ca: Option<PathBuf>,
+    /// Domain name checked against the server certificate; unset uses the
+    /// host from the connection address.
+    pub domain: Option<String>,
+    /// PEM encoded client certificate and private key, presented when the
+    /// server requires mutual TLS.
+    pub identity: Option<(PathBuf, PathBuf)>,
+}
+
+impl TlsFiles {
+    /// Read the referenced files and build the tonic TLS config, reporting
+    /// missing or non-PEM files before any connection is attempted.
+    pub fn load(&self) -> Result<ClientTlsConfig, Error> {
+        let mut config = ClientTlsConfig::new();
+        if let Some(ca) = &self.ca {
+            config = config.ca_certificate(Certificate::from_pem(read_pem(ca)?));
+        }
+        if let Some(domain) = &self.domain {
+            config = config.domain_name(domain);
+        }
+        if let Some((cert, key)) = &self.identity {
+            config = config.identity(Identity::from_pem(read_pem(cert)?, read_pem(key)?));
+        }
+        Ok(config)
+    }
+}
+
+/// Read a file that must contain PEM data, naming the path in the error so
+/// a misconfigured deployment says which file is the problem.
+fn read_pem(path: &Path) -> Result<Vec<u8>, Error> {
+    let data = std::fs::read(path)
+        .map_err(|e| Error::Tls(format!("failed to read {}: {e}", path.display())))?;
+    const MARKER: &[u8] = b"-----BEGIN ";
+    if !data.windows(MARKER.len()).any(|window| window == MARKER) {
+        return Err(Error::Tls(format!("no PEM data in {}", path.display())));
+    }
+    Ok(data)
+}
+
+/// An ergonomic wrapper around the generated gRPC client: it builds the
+/// protobuf messages internally so callers work with chrono types and plain
+/// strings instead of prost structs.
+#[derive(Debug, Clone)]
+pub struct ReservationClient {
+    inner: ReservationServiceClient<Channel>,
+}
+
+impl ReservationClient {
+    /// Connect with the default [`ClientConfig`] (plaintext, no timeout).
+    pub async fn connect(addr: impl Into<String>) -> Result<Self, Error> {
+        Self::connect_with(addr, ClientConfig::default()).await
+    }
+
+    /// Connect with explicit timeout / TLS settings.
+    pub async fn connect_with(
+        addr: impl Into<String>,
+        config: ClientConfig,
+    ) -> Result<Self, Error> {
+        let mut endpoint = Endpoint::from_shared(addr.into())?;
+        if let Some(timeout) = config.timeout {
+            endpoint = endpoint.timeout(timeout);
+        }
+        if let Some(tls) = config.tls {
+            endpoint = endpoint.tls_config(tls)?;
+        }
+        let channel = endpoint.connect().await?;
+        Ok(Self {
+            inner: ReservationServiceClient::new(channel),
+        })
+    }
+
+    /// Make a pending reservation for the given window. Pass a non-empty
+    /// `idempotency_key` to make retries safe: a repeated key returns the
+    /// reservation created the first time.
+    pub async fn reserve(
+        &mut self,
+        user_id: impl Into<String>,
+        resource_id: impl Into<String>,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+        note: impl Into<String>,
+        idempotency_key: impl Into<String>,
+    ) -> Result<Reservation, Error> {
+        let rsvp = Reservation::new_pending(user_id, resource_id, start, end, note);
+        let response = self
+            .inner
+            .reserve(ReserveRequest {
+                reservation: Some(rsvp),
+                idempotency_key: idempotency_key.into(),
+            })
+            .await?
+            .into_inner();
+        response
+            .reservation
+            .ok_or(Error::MissingField("reservation"))
+    }
+
+    /// Block out a maintenance window on a resource.
+    pub async fn block(
+        &mut self,
+        resource_id: impl Into<String>,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+        note: impl Into<String>,
+    ) -> Result<Reservation, Error> {
+        let response = self
+            .inner
+            .block(BlockRequest {
+                resource_id: resource_id.into(),
+                start: Some(convert_to_timestamp(&start)),
+                end: Some(convert_to_timestamp(&end)),
+                note: note.into(),
+            })
+            .await?
+            .into_inner();
+        response
+            .reservation
+            .ok_or(Error::MissingField("reservation"))
+    }
+
+    /// Confirm a pending reservation.
+    pub async fn confirm(&mut self, id: impl Into<String>) -> Result<Reservation, Error> {
+        let response = self
+            .inner
+            .confirm(ConfirmRequest { id: id.into() })
+            .await?
+            .into_inner();
+        response
+            .reservation
+            .ok_or(Error::MissingField("reservation"))
+    }
+
+    /// Cancel a reservation.
+    pub async fn cancel(&mut self, id: impl Into<String>) -> Result<Reservation, Error> {
+        let response = self
+            .inner
+            .cancel(CancelRequest { id: id.into() })
+            .await?
+            .into_inner();
+        response
+            .reservation
+            .ok_or(Error::MissingField("reservation"))
+    }
+
+    /// Get a reservation by id.
+    pub async fn get(&mut self, id: impl Into<String>) -> Result<Reservation, Error> {
+        let response = self
+            .inner
+            .get(GetRequest { id: id.into() })
+            .await?
+            .into_inner();
+        response
+            .reservation
+            .ok_or(Error::MissingField("reservation"))
+    }
+
+    /// Stream all reservations matching the query.
+    pub async fn query(
+        &mut self,
+        query: ReservationQuery,
+    ) -> Result<impl Stream<Item = Result<Reservation, Error>>, Error> {
+        let stream = self
+            .inner
+            .query(QueryRequest { query: Some(query) })
+            .await?
+            .into_inner();
+        Ok(stream.map(|item| item.map_err(Error::from)))
+    }
+
+    /// Fetch one page of reservations.
+    pub async fn filter(&mut self, filter: ReservationFilter) -> Result<FilterResponse, Error> {
+        let response = self
+            .inner
+            .filter(FilterRequest {
+                filter: Some(filter),
+            })
+            .await?
+            .into_inner();
+        Ok(response)
+    }
+
+    /// Dry-run availability check for a window on a resource.
+    pub async fn check_availability(
+        &mut self,
+        resource_id: impl Into<String>,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+    ) -> Result<CheckAvailabilityResponse, Error> {
+        let response = self
+            .inner
+            .check_availability(CheckAvailabilityRequest {
+                resource_id: resource_id.into(),
+                start: Some(convert_to_timestamp(&start)),
+                end: Some(convert_to_timestamp(&end)),
+            })
+            .await?
+            .into_inner();
+        Ok(response)
+    }
+
+    /// Count reservations matching the query; `None` counts everything.
+    pub async fn count(&mut self, query: Option<ReservationQuery>) -> Result<i64, Error> {
+        let response = self.inner.count(CountRequest { query }).await?.into_inner();
+        Ok(response.count)
+    }
+
+    /// Stream reservation changes as they happen; pass the last seen
+    /// `change_id` as `resume_from` to replay missed changes, or 0 to start
+    /// from now.
+    pub async fn watch(
+        &mut self,
+        resume_from: i64,
+    ) -> Result<impl Stream<Item = Result<WatchResponse, Error>>, Error> {
+        let stream = self
+            .inner
+            .watch(WatchRequest { resume_from })
+            .await?
+            .into_inner();
+        Ok(stream.map(|item| item.map_err(Error::from)))
+    }
+}
@@ -0,0 +1,29 @@
+mod client;
+
+use thiserror::Error;
+
+pub use client::{ClientConfig, ReservationClient, TlsFiles};
+
+/// Errors surfaced by the client wrapper: connection setup failures,
+/// server-side statuses, and responses missing a required field.
+#[derive(Error, Debug)]
+pub enum Error {
+    #[error("transport error: {0}")]
+    Transport(#[from] tonic::transport::Error),
+
+    #[error("rpc error: {0}")]
+    Rpc(Box<tonic::Status>),
+
+    #[error("missing {0} in the response")]
+    MissingField(&'static str),
+
+    #[error("tls setup error: {0}")]
+    Tls(String),
+}
+
+// boxed so `Result<_, Error>` stays small despite the size of `Status`
+impl From<tonic::Status> for Error {
+    fn from(status: tonic::Status) -> Self {
+        Self::Rpc(Box::new(status))
+    }
+}
@@ -0,0 +1,21 @@
+-- extensions are database-global; pin them to public so every tenant
+-- schema on the search_path can use their operators
+CREATE EXTENSION IF NOT EXISTS btree_gist SCHEMA public;
+
+CREATE TYPE reservation_status AS ENUM ('unknown', 'pending', 'confirmed', 'blocked');
+
+CREATE TABLE reservations (
+    id uuid NOT NULL DEFAULT gen_random_uuid(),
+    user_id varchar(64) NOT NULL,
+    resource_id varchar(64) NOT NULL,
+    timespan tstzrange NOT NULL,
+    status reservation_status NOT NULL DEFAULT 'pending',
+    note text,
+
+    CONSTRAINT pk_reservations PRIMARY KEY (id),
+    CONSTRAINT reservations_conflict EXCLUDE USING gist (resource_id WITH =, timespan WITH &&)
+);
+
+CREATE INDEX idx_reservations_user_id ON reservations (user_id);
+
+CREATE INDEX idx_reservations_resource_id ON reservations (resource_id);
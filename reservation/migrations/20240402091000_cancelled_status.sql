@@ -0,0 +1,3 @@
+-- kept separate from the migration that uses it: a new enum value cannot be
+-- used inside the transaction that adds it
+ALTER TYPE reservation_status ADD VALUE 'cancelled';
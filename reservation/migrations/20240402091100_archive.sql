@@ -0,0 +1,8 @@
+ALTER TABLE reservations ADD COLUMN archived_at timestamptz;
+
+-- cancelled reservations no longer occupy their window, so a cancelled slot
+-- can be rebooked
+ALTER TABLE reservations DROP CONSTRAINT reservations_conflict;
+ALTER TABLE reservations ADD CONSTRAINT reservations_conflict
+    EXCLUDE USING gist (resource_id WITH =, timespan WITH &&)
+    WHERE (status <> 'cancelled');
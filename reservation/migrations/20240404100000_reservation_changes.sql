@@ -0,0 +1,37 @@
+-- persist reservation changes so a watcher can resume from a change id
+CREATE TYPE reservation_update_type AS ENUM ('create', 'update', 'delete');
+
+CREATE TABLE reservation_changes (
+    id bigserial PRIMARY KEY,
+    reservation_id uuid NOT NULL,
+    op reservation_update_type NOT NULL
+);
+
+-- log every change and wake up live watchers; the payload is the change id
+CREATE OR REPLACE FUNCTION reservations_trigger() RETURNS trigger AS
+$$
+DECLARE
+    change_id bigint;
+BEGIN
+    IF TG_OP = 'INSERT' THEN
+        INSERT INTO reservation_changes (reservation_id, op)
+        VALUES (NEW.id, 'create')
+        RETURNING id INTO change_id;
+    ELSIF TG_OP = 'UPDATE' THEN
+        INSERT INTO reservation_changes (reservation_id, op)
+        VALUES (NEW.id, 'update')
+        RETURNING id INTO change_id;
+    ELSE
+        INSERT INTO reservation_changes (reservation_id, op)
+        VALUES (OLD.id, 'delete')
+        RETURNING id INTO change_id;
+    END IF;
+    PERFORM pg_notify('reservation_update', change_id::text);
+    RETURN NULL;
+END;
+$$ LANGUAGE plpgsql;
+
+CREATE TRIGGER reservations_trigger
+    AFTER INSERT OR UPDATE OR DELETE ON reservations
+    FOR EACH ROW
+    EXECUTE FUNCTION reservations_trigger();
@@ -0,0 +1,3 @@
+-- serve "next reservations on a resource" lookups from an index walk
+CREATE INDEX reservations_resource_status_start_idx
+    ON reservations (resource_id, status, lower(timespan));
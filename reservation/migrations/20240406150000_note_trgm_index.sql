@@ -0,0 +1,5 @@
+-- trigram index so ILIKE '%...%' note searches don't have to seq-scan
+CREATE EXTENSION IF NOT EXISTS pg_trgm SCHEMA public;
+
+CREATE INDEX reservations_note_trgm_idx
+    ON reservations USING gin (note gin_trgm_ops);
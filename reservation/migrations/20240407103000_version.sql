@@ -0,0 +1,4 @@
+-- Optimistic concurrency: version starts at 1 and is bumped by every
+-- mutating statement, so clients can detect concurrent edits.
+ALTER TABLE reservations
+    ADD COLUMN version integer NOT NULL DEFAULT 1;
@@ -0,0 +1,10 @@
+-- Holds: pending reservations with an expiry that the sweeper releases
+-- automatically. NULL means the reservation never expires.
+ALTER TABLE reservations
+    ADD COLUMN expires_at timestamptz;
+
+-- the sweeper only ever looks at unexpired pending holds
+CREATE INDEX reservations_expiry_idx ON reservations (expires_at)
+WHERE
+    status = 'pending'
+    AND expires_at IS NOT NULL;
@@ -0,0 +1,10 @@
+-- Client-supplied idempotency keys make retried reserves converge on the
+-- row the first attempt created. NULL means no key was supplied.
+ALTER TABLE reservations
+    ADD COLUMN idempotency_key text;
+
+-- partial unique index: keyless rows stay unconstrained, and it doubles as
+-- the arbiter for INSERT ... ON CONFLICT (idempotency_key)
+CREATE UNIQUE INDEX reservations_idempotency_key_idx ON reservations (idempotency_key)
+WHERE
+    idempotency_key IS NOT NULL;
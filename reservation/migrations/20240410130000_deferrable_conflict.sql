@@ -0,0 +1,7 @@
+-- Bulk imports defer the conflict check to one batch-wide pass at commit;
+-- immediate checking stays the default for normal traffic.
+ALTER TABLE reservations DROP CONSTRAINT reservations_conflict;
+ALTER TABLE reservations ADD CONSTRAINT reservations_conflict
+    EXCLUDE USING gist (resource_id WITH =, timespan WITH &&)
+    WHERE (status <> 'cancelled')
+    DEFERRABLE INITIALLY IMMEDIATE;
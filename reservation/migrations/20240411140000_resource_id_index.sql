@@ -0,0 +1,4 @@
+-- Multi-resource queries filter with resource_id = ANY($1); the exclusion
+-- constraint's gist index keys on (resource_id, timespan) and is a poor fit
+-- for plain equality, so give these scans a btree of their own.
+CREATE INDEX reservations_resource_id_idx ON reservations (resource_id);
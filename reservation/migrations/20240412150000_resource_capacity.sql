@@ -0,0 +1,16 @@
+-- Per-resource overlap policy: a resource may hold up to `capacity`
+-- overlapping reservations at once (a parking lot with 20 spots). Resources
+-- without a row stay exclusive (capacity 1), matching the old behavior.
+CREATE TABLE resources (
+    id text PRIMARY KEY,
+    capacity integer NOT NULL DEFAULT 1 CHECK (capacity >= 1)
+);
+
+-- An exclusion constraint can only express capacity 1, so overlap control
+-- moves into the reserve transaction: count overlapping active rows under a
+-- per-resource advisory lock and reject at capacity. Keep a gist index so
+-- those overlap counts stay fast.
+ALTER TABLE reservations
+    DROP CONSTRAINT reservations_conflict;
+
+CREATE INDEX reservations_overlap_idx ON reservations USING gist (resource_id, timespan);
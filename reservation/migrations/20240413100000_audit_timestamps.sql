@@ -0,0 +1,21 @@
+-- audit timestamps: when a reservation was created and last changed.
+-- Existing rows get the migration time for both; their real history is
+-- unknown.
+ALTER TABLE reservations
+    ADD COLUMN created_at timestamptz NOT NULL DEFAULT now(),
+    ADD COLUMN updated_at timestamptz NOT NULL DEFAULT now();
+
+-- a trigger rather than an explicit SET in every statement, so no code path
+-- (including ad-hoc SQL) can forget to advance updated_at
+CREATE OR REPLACE FUNCTION reservations_touch_updated_at() RETURNS trigger AS
+$$
+BEGIN
+    NEW.updated_at = now();
+    RETURN NEW;
+END;
+$$ LANGUAGE plpgsql;
+
+CREATE TRIGGER reservations_touch_updated_at
+    BEFORE UPDATE ON reservations
+    FOR EACH ROW
+    EXECUTE FUNCTION reservations_touch_updated_at();
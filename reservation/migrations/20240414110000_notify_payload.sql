@@ -0,0 +1,69 @@
+-- carry the full changed row in the NOTIFY payload so a live watcher does
+-- not need a follow-up SELECT per change. NOTIFY payloads are capped at
+-- 8000 bytes, so an oversized change (e.g. a huge note) falls back to the
+-- bare change id and the listener re-syncs from the change log.
+CREATE OR REPLACE FUNCTION reservations_trigger() RETURNS trigger AS
+$$
+DECLARE
+    change_id bigint;
+    payload text;
+BEGIN
+    IF TG_OP = 'INSERT' THEN
+        INSERT INTO reservation_changes (reservation_id, op)
+        VALUES (NEW.id, 'create')
+        RETURNING id INTO change_id;
+        payload := json_build_object(
+            'change_id', change_id,
+            'op', 'create',
+            'reservation', json_build_object(
+                'id', NEW.id,
+                'user_id', NEW.user_id,
+                'resource_id', NEW.resource_id,
+                'start', lower(NEW.timespan),
+                'end', upper(NEW.timespan),
+                'status', NEW.status,
+                'note', NEW.note,
+                'version', NEW.version,
+                'created_at', NEW.created_at,
+                'updated_at', NEW.updated_at
+            )
+        )::text;
+    ELSIF TG_OP = 'UPDATE' THEN
+        INSERT INTO reservation_changes (reservation_id, op)
+        VALUES (NEW.id, 'update')
+        RETURNING id INTO change_id;
+        payload := json_build_object(
+            'change_id', change_id,
+            'op', 'update',
+            'reservation', json_build_object(
+                'id', NEW.id,
+                'user_id', NEW.user_id,
+                'resource_id', NEW.resource_id,
+                'start', lower(NEW.timespan),
+                'end', upper(NEW.timespan),
+                'status', NEW.status,
+                'note', NEW.note,
+                'version', NEW.version,
+                'created_at', NEW.created_at,
+                'updated_at', NEW.updated_at
+            )
+        )::text;
+    ELSE
+        INSERT INTO reservation_changes (reservation_id, op)
+        VALUES (OLD.id, 'delete')
+        RETURNING id INTO change_id;
+        payload := json_build_object(
+            'change_id', change_id,
+            'op', 'delete',
+            'reservation', NULL
+        )::text;
+    END IF;
+    -- stay well under the 8000-byte cap; the listener treats a bare id as
+    -- "fetch from the change log"
+    IF octet_length(payload) > 7500 THEN
+        payload := change_id::text;
+    END IF;
+    PERFORM pg_notify('reservation_update', payload);
+    RETURN NULL;
+END;
+$$ LANGUAGE plpgsql;
@@ -0,0 +1,8 @@
+-- the "next reservations" query filters status with <>, which cannot
+-- constrain a middle index column, so the old index degenerated into a
+-- scan over every status for the resource. A partial index keyed to
+-- non-cancelled rows serves the lookup as an ordered index walk again.
+DROP INDEX reservations_resource_status_start_idx;
+CREATE INDEX reservations_resource_active_start_idx
+    ON reservations (resource_id, lower(timespan))
+    WHERE status <> 'cancelled';
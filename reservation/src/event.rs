@@ -0,0 +1,79 @@
+use std::{fmt, sync::Mutex};
+
+use abi::{Reservation, ReservationChangeType};
+use async_trait::async_trait;
+
+/// An event describing one committed mutation, pushed to the configured sink.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ReservationEvent {
+    pub change_type: ReservationChangeType,
+    pub reservation: Reservation,
+}
+
+/// Destination for reservation events, e.g. an adapter onto a message bus.
+/// Events are published after the mutation commits, so a published event
+/// always refers to durable state; a slow sink delays the response but a
+/// failed commit never publishes.
+#[async_trait]
+pub trait EventSink: Send + Sync + fmt::Debug {
+    async fn publish(&self, event: ReservationEvent);
+}
+
+/// A sink that discards every event.
+#[derive(Debug, Default)]
+pub struct NoopSink;
+
+#[async_trait]
+impl EventSink for NoopSink {
+    async fn publish(&self, _event: ReservationEvent) {}
+}
+
+/// Collects events in memory so tests can assert what fired, and in what
+/// order.
+#[derive(Debug, Default)]
+pub struct MemorySink {
+    events: Mutex<Vec<ReservationEvent>>,
+}
+
+impl MemorySink {
+    pub fn events(&self) -> Vec<ReservationEvent> {
+        self.events.lock().unwrap().clone()
+    }
+}
+
+#[async_trait]
+impl EventSink for MemorySink {
+    async fn publish(&self, event: ReservationEvent) {
+        self.events.lock().unwrap().push(event);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{TimeZone, Utc};
+
+    #[tokio::test]
+    async fn memory_sink_should_collect_events_in_order() {
+        let sink = MemorySink::default();
+        let rsvp = Reservation::new_pending(
+            "alice",
+            "room-101",
+            Utc.with_ymd_and_hms(2024, 3, 26, 10, 0, 0).unwrap(),
+            Utc.with_ymd_and_hms(2024, 3, 26, 12, 0, 0).unwrap(),
+            "test",
+        );
+        for change_type in [ReservationChangeType::Create, ReservationChangeType::Update] {
+            sink.publish(ReservationEvent {
+                change_type,
+                reservation: rsvp.clone(),
+            })
+            .await;
+        }
+
+        let events = sink.events();
+        assert_eq!(events.len(), 2);
+        assert_eq!(events[0].change_type, ReservationChangeType::Create);
+        assert_eq!(events[1].change_type, ReservationChangeType::Update);
+    }
+}
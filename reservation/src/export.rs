@@ -0,0 +1,174 @@
+use abi::{convert_to_utc_time, Reservation};
+use std::borrow::Cow;
+
+/// Serialization format for [`ReservationManager::export`].
+///
+/// [`ReservationManager::export`]: crate::ReservationManager::export
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExportFormat {
+    /// One JSON array streamed in pieces; concatenating the chunks yields a
+    /// valid document.
+    Json,
+    /// One CSV line per reservation, preceded by a header row; timestamps are
+    /// ISO 8601.
+    Csv,
+}
+
+/// Column order for CSV exports.
+const CSV_HEADER: &str = "id,user_id,resource_id,start,end,status,note";
+
+/// Turns reservations into chunks of the chosen format, one row at a time, so
+/// exports never hold more than a page in memory. Feed every row through
+/// [`chunk`](Exporter::chunk), then append [`finish`](Exporter::finish).
+pub(crate) struct Exporter {
+    format: ExportFormat,
+    rows: usize,
+}
+
+impl Exporter {
+    pub(crate) fn new(format: ExportFormat) -> Self {
+        Self { format, rows: 0 }
+    }
+
+    pub(crate) fn chunk(&mut self, rsvp: &Reservation) -> String {
+        let first = self.rows == 0;
+        self.rows += 1;
+        match self.format {
+            ExportFormat::Json => {
+                // only strings and integers below this point, so
+                // serialization cannot fail
+                let row = serde_json::to_string(rsvp).expect("reservation serializes");
+                if first {
+                    format!("[{row}")
+                } else {
+                    format!(",{row}")
+                }
+            }
+            ExportFormat::Csv => {
+                let row = csv_row(rsvp);
+                if first {
+                    format!("{CSV_HEADER}\n{row}\n")
+                } else {
+                    format!("{row}\n")
+                }
+            }
+        }
+    }
+
+    pub(crate) fn finish(&mut self) -> Option<String> {
+        match self.format {
+            ExportFormat::Json if self.rows == 0 => Some("[]".to_string()),
+            ExportFormat::Json => Some("]".to_string()),
+            // an empty CSV export still gets its header
+            ExportFormat::Csv if self.rows == 0 => Some(format!("{CSV_HEADER}\n")),
+            ExportFormat::Csv => None,
+        }
+    }
+}
+
+fn csv_row(rsvp: &Reservation) -> String {
+    let start = rsvp
+        .start
+        .as_ref()
+        .map(|ts| convert_to_utc_time(ts).to_rfc3339())
+        .unwrap_or_default();
+    let end = rsvp
+        .end
+        .as_ref()
+        .map(|ts| convert_to_utc_time(ts).to_rfc3339())
+        .unwrap_or_default();
+    // "pending" rather than "RESERVATION_STATUS_PENDING", matching the
+    // database enum
+    let status = rsvp
+        .status()
+        .as_str_name()
+        .trim_start_matches("RESERVATION_STATUS_")
+        .to_lowercase();
+    [
+        csv_escape(&rsvp.id),
+        csv_escape(&rsvp.user_id),
+        csv_escape(&rsvp.resource_id),
+        Cow::Owned(start),
+        Cow::Owned(end),
+        Cow::Owned(status),
+        csv_escape(&rsvp.note),
+    ]
+    .join(",")
+}
+
+/// Quote a field when it contains a comma, quote, or newline, doubling any
+/// embedded quotes (RFC 4180).
+fn csv_escape(field: &str) -> Cow<'_, str> {
+    if field.contains(['"', ',', '\n', '\r']) {
+        Cow::Owned(format!("\"{}\"", field.replace('"', "\"\"")))
+    } else {
+        Cow::Borrowed(field)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use abi::convert_to_timestamp;
+    use chrono::{TimeZone, Utc};
+
+    fn rsvp(id: &str, note: &str) -> Reservation {
+        Reservation {
+            id: id.to_string(),
+            user_id: "alice".to_string(),
+            resource_id: "room-42".to_string(),
+            start: Some(convert_to_timestamp(
+                &Utc.with_ymd_and_hms(2024, 3, 26, 10, 0, 0).unwrap(),
+            )),
+            end: Some(convert_to_timestamp(
+                &Utc.with_ymd_and_hms(2024, 3, 26, 12, 0, 0).unwrap(),
+            )),
+            note: note.to_string(),
+            ..Default::default()
+        }
+    }
+
+    fn export(format: ExportFormat, rsvps: &[Reservation]) -> String {
+        let mut exporter = Exporter::new(format);
+        let mut out = String::new();
+        for rsvp in rsvps {
+            out.push_str(&exporter.chunk(rsvp));
+        }
+        if let Some(tail) = exporter.finish() {
+            out.push_str(&tail);
+        }
+        out
+    }
+
+    #[test]
+    fn csv_export_should_have_a_header_and_one_line_per_row() {
+        let out = export(ExportFormat::Csv, &[rsvp("a", "lunch"), rsvp("b", "")]);
+        let lines: Vec<_> = out.lines().collect();
+        assert_eq!(lines.len(), 3);
+        assert_eq!(lines[0], CSV_HEADER);
+        assert_eq!(
+            lines[1],
+            "a,alice,room-42,2024-03-26T10:00:00+00:00,2024-03-26T12:00:00+00:00,unknown,lunch"
+        );
+    }
+
+    #[test]
+    fn csv_export_should_escape_commas_and_quotes_in_notes() {
+        let out = export(ExportFormat::Csv, &[rsvp("a", "lunch, \"private\"")]);
+        assert!(out.ends_with(",\"lunch, \"\"private\"\"\"\n"));
+    }
+
+    #[test]
+    fn empty_exports_should_still_be_well_formed() {
+        assert_eq!(export(ExportFormat::Csv, &[]), format!("{CSV_HEADER}\n"));
+        assert_eq!(export(ExportFormat::Json, &[]), "[]");
+    }
+
+    #[test]
+    fn json_chunks_should_concatenate_to_a_valid_array() {
+        let rsvps = vec![rsvp("a", "one"), rsvp("b", "two, \"quoted\"")];
+        let out = export(ExportFormat::Json, &rsvps);
+        let back: Vec<Reservation> = serde_json::from_str(&out).unwrap();
+        assert_eq!(back, rsvps);
+    }
+}
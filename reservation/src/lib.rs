@@ -1,14 +1,182 @@
-pub fn add(left: usize, right: usize) -> usize {
-    left + right
-}
+mod event;
+mod export;
+pub mod metrics;
+mod store;
+#[cfg(feature = "test-util")]
+pub mod test_util;
+
+use abi::{
+    BulkImportResponse, CalendarSlot, Error, FilterResponse, PollChangesResponse, Reservation,
+    ReservationFilter, ReservationInfo, ReservationQuery, UpdateRequest, WatchResponse,
+};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use tokio::sync::mpsc;
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+pub use event::{EventSink, MemorySink, NoopSink, ReservationEvent};
+pub use export::ExportFormat;
+pub use store::{
+    PgStore, StoreConfig, BLOCK_USER_ID, MAX_CALENDAR_SLOTS, MAX_CHANGES_PER_POLL,
+    MAX_POLL_WAIT_SECS, MIGRATOR,
+};
 
-    #[test]
-    fn it_works() {
-        let result = add(2, 2);
-        assert_eq!(result, 4);
-    }
+/// The core reservation behavior, backed by `PgStore` in production.
+#[async_trait]
+pub trait ReservationManager {
+    /// Make a reservation. A non-empty `idempotency_key` makes retries safe:
+    /// a repeated key returns the reservation the first attempt created
+    /// instead of inserting a duplicate.
+    async fn reserve(&self, rsvp: Reservation, idempotency_key: &str)
+        -> Result<Reservation, Error>;
+    /// Block out a maintenance window on a resource. The blocked span is a
+    /// `Blocked` reservation under the sentinel [`BLOCK_USER_ID`]; it counts
+    /// against the resource's capacity like any other active reservation, so
+    /// a block on an exclusive resource keeps users from booking over it.
+    async fn block(
+        &self,
+        resource_id: &str,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+        note: &str,
+    ) -> Result<Reservation, Error>;
+    /// Tentatively hold a slot for a checkout flow: a pending reservation
+    /// that the sweeper releases automatically unless it is confirmed within
+    /// `ttl` (confirming clears the expiry). The sweeper must be running for
+    /// holds to actually expire; see `PgStore::start_sweeper`.
+    async fn hold(
+        &self,
+        info: ReservationInfo,
+        ttl: chrono::Duration,
+    ) -> Result<Reservation, Error>;
+    /// Make several reservations in one transaction; if any conflicts, the
+    /// whole batch is rolled back.
+    async fn batch_reserve(&self, infos: Vec<ReservationInfo>) -> Result<Vec<Reservation>, Error>;
+    /// Confirm a pending reservation; any other current status is an
+    /// invalid transition.
+    async fn confirm(&self, id: &str) -> Result<Reservation, Error>;
+    /// Apply the fields selected by `update_mask`; an empty mask returns the
+    /// reservation unchanged.
+    async fn update(&self, update: UpdateRequest) -> Result<Reservation, Error>;
+    /// Move a reservation to a new time window, keeping its status unchanged.
+    /// A non-zero `expected_version` makes the move conditional on the
+    /// reservation still having that version.
+    async fn reschedule(
+        &self,
+        id: &str,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+        expected_version: i32,
+    ) -> Result<Reservation, Error>;
+    /// Cut a reservation in two at `at`, which must lie strictly inside its
+    /// window. The original is shortened to `[start, at)` and a new
+    /// reservation covers `[at, end)` with the same resource, user, status
+    /// and note; both commit in one transaction. Returns the shortened
+    /// original and the new second half.
+    async fn split(&self, id: &str, at: DateTime<Utc>)
+        -> Result<(Reservation, Reservation), Error>;
+    /// Replace two adjacent or overlapping reservations for the same
+    /// resource and user with one spanning their combined range, in one
+    /// transaction. The merged reservation takes its status and note from
+    /// the earlier-starting half. A gap between the two, or differing
+    /// resource/user, fails with `Error::NotMergeable`.
+    async fn merge(&self, id_a: &str, id_b: &str) -> Result<Reservation, Error>;
+    /// Cancel a pending or confirmed reservation; the row is kept for audit
+    /// with status CANCELLED.
+    async fn cancel(&self, id: &str) -> Result<Reservation, Error>;
+    /// Archive a cancelled reservation so it drops out of default queries.
+    async fn archive(&self, id: &str) -> Result<Reservation, Error>;
+    /// Get a reservation by id.
+    async fn get(&self, id: &str) -> Result<Reservation, Error>;
+    /// Query all reservations matching the criteria, paging internally.
+    async fn query(&self, query: ReservationQuery) -> Result<Vec<Reservation>, Error>;
+    /// Fetch one page of reservations; `next_cursor` in the response is empty
+    /// when there are no more pages.
+    async fn filter(&self, filter: ReservationFilter) -> Result<FilterResponse, Error>;
+    /// The soonest `limit` non-cancelled reservations on the resource that
+    /// start after `now`, ordered by start time. Empty when nothing is
+    /// upcoming. `now` is a parameter so callers (and tests) control what
+    /// "future" means.
+    async fn next_reservations(
+        &self,
+        resource_id: &str,
+        now: DateTime<Utc>,
+        limit: i64,
+    ) -> Result<Vec<Reservation>, Error>;
+    /// Count reservations matching the criteria without fetching any rows.
+    async fn count(&self, query: ReservationQuery) -> Result<i64, Error>;
+    /// Count reservations per resource for the criteria, busiest first.
+    /// `top_n > 0` keeps only the busiest n resources.
+    async fn aggregate_by_resource(
+        &self,
+        query: ReservationQuery,
+        top_n: i32,
+    ) -> Result<Vec<(String, i64)>, Error>;
+    /// Bucket the resource's reservations between `start` and `end` into
+    /// `slot`-sized slots, each marked free, reserved or blocked. Any overlap
+    /// occupies the slot; blocked wins over reserved. Only whole slots are
+    /// returned and at most [`MAX_CALENDAR_SLOTS`] of them.
+    async fn calendar(
+        &self,
+        resource_id: &str,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+        slot: chrono::Duration,
+    ) -> Result<Vec<CalendarSlot>, Error>;
+    /// Dry-run availability check: returns the ids of active reservations
+    /// overlapping the window (empty means available), mutating nothing. The
+    /// answer can be stale by the time a real reserve runs.
+    async fn check_availability(
+        &self,
+        resource_id: &str,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+    ) -> Result<Vec<String>, Error>;
+    /// Load a stream of historical reservations over Postgres COPY, far
+    /// faster than per-row inserts. Rows failing validation are skipped and
+    /// counted rather than aborting the load. Unless `allow_overlaps` is
+    /// set, one batch-wide capacity pass runs before commit and a resource
+    /// pushed over capacity rolls back the whole import; historical data
+    /// with overlaps should come in with status CANCELLED or set the flag.
+    async fn bulk_import(
+        &self,
+        infos: mpsc::Receiver<ReservationInfo>,
+        allow_overlaps: bool,
+    ) -> Result<BulkImportResponse, Error>;
+    /// Delete every reservation matching `query` in one statement and return
+    /// the number removed. A query that narrows nothing is refused unless
+    /// `confirm_delete_all` is set, so a default request cannot wipe the
+    /// table by accident. Destructive and admin-only; the change-log trigger
+    /// records each deletion for watchers.
+    async fn delete_by_query(
+        &self,
+        query: ReservationQuery,
+        confirm_delete_all: bool,
+    ) -> Result<u64, Error>;
+    /// Stream the reservations matching `query`, serialized as `format`
+    /// chunks. Pages are fetched with the keyset cursor as the receiver
+    /// drains, so memory stays bounded however large the result set is.
+    async fn export(
+        &self,
+        query: ReservationQuery,
+        format: ExportFormat,
+    ) -> Result<mpsc::Receiver<Result<String, Error>>, Error>;
+    /// Stream reservation changes. Persisted changes with id greater than
+    /// `resume_from` are replayed first, then the stream goes live; no change
+    /// is delivered twice or skipped across the transition.
+    async fn watch(
+        &self,
+        resume_from: i64,
+    ) -> Result<mpsc::Receiver<Result<WatchResponse, Error>>, Error>;
+    /// Long-poll variant of `watch` for clients that cannot hold a stream
+    /// open: return the changes with id greater than `resume_from` — at most
+    /// [`MAX_CHANGES_PER_POLL`] per call, `next_resume_from` pages through a
+    /// longer backlog — or when there are none, wait up to `wait` (capped at
+    /// [`MAX_POLL_WAIT_SECS`]) for one before returning empty with the
+    /// cursor unchanged. The wait blocks on NOTIFY rather than re-querying,
+    /// so rapid empty polls do not busy-spin the database.
+    async fn poll_changes(
+        &self,
+        resume_from: i64,
+        wait: chrono::Duration,
+    ) -> Result<PollChangesResponse, Error>;
 }
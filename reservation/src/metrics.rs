@@ -0,0 +1,52 @@
+//! SLO instrumentation through the `metrics` facade. Every call is a no-op
+//! until the binary installs a recorder (the service binary exposes a
+//! Prometheus endpoint). Labels are limited to the operation name and a
+//! coarse outcome so cardinality stays bounded — never reservation ids or
+//! other per-row values.
+
+use std::time::Duration;
+
+use abi::Error;
+
+/// Counter of finished operations, labelled `op` and `status`
+/// (`ok` / `conflict` / `error`).
+pub const OPERATIONS: &str = "reservation_operations_total";
+
+/// Histogram of database time per operation in seconds, labelled `op`.
+pub const DB_SECONDS: &str = "reservation_db_seconds";
+
+/// Gauge of reservations currently pending or confirmed, refreshed by
+/// `PgStore::start_metrics`.
+pub const ACTIVE: &str = "reservation_active";
+
+/// Register help text for the metrics above; call once after installing a
+/// recorder.
+pub fn describe() {
+    metrics::describe_counter!(
+        OPERATIONS,
+        "Finished reservation operations by op and ok/conflict/error status"
+    );
+    metrics::describe_histogram!(
+        DB_SECONDS,
+        metrics::Unit::Seconds,
+        "Database time spent per reservation operation"
+    );
+    metrics::describe_gauge!(ACTIVE, "Reservations currently pending or confirmed");
+}
+
+/// Record one finished operation: the outcome counter plus the latency
+/// histogram. Conflicts get their own status since they drive capacity
+/// alerting.
+pub(crate) fn observe<T>(op: &'static str, elapsed: Duration, result: &Result<T, Error>) {
+    let status = match result {
+        Ok(_) => "ok",
+        Err(Error::ConflictReservation(_)) => "conflict",
+        Err(_) => "error",
+    };
+    metrics::counter!(OPERATIONS, "op" => op, "status" => status).increment(1);
+    metrics::histogram!(DB_SECONDS, "op" => op).record(elapsed.as_secs_f64());
+}
+
+pub(crate) fn set_active(count: i64) {
+    metrics::gauge!(ACTIVE).set(count as f64);
+}
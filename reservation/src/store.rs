@@ -0,0 +1,2293 @@
+use abi::{
+    escape_like, parse_reservation_id, query_range, statuses_to_db, validate_max_duration,
+    validate_range, validate_schema_name, BulkImportResponse, CalendarSlot, Error, FilterResponse,
+    PollChangesResponse, Reservation, ReservationChangeType, ReservationConflictInfo,
+    ReservationFilter, ReservationInfo, ReservationQuery, ReservationStatus, RsvpStatus,
+    SlotStatus, UpdateField, UpdateRequest, Validate, WatchResponse,
+};
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    future::Future,
+    sync::Arc,
+    time::Duration,
+};
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::{
+    postgres::{types::PgRange, PgConnectOptions, PgListener, PgPoolOptions, PgRow},
+    types::Uuid,
+    FromRow, PgConnection, PgPool, QueryBuilder, Row,
+};
+use tokio::sync::mpsc;
+
+use crate::{export::Exporter, EventSink, ExportFormat, ReservationEvent, ReservationManager};
+
+const RESERVATION_COLUMNS: &str =
+    "id, user_id, resource_id, timespan, status, note, version, created_at, updated_at";
+
+/// The NOTIFY channel the change-log trigger publishes on.
+const CHANGE_CHANNEL: &str = "reservation_update";
+
+/// Sentinel user id recorded on maintenance blocks.
+pub const BLOCK_USER_ID: &str = "system";
+
+/// Upper bound on the slots a single calendar call may generate, so a wide
+/// range with a tiny granularity cannot produce an enormous response.
+pub const MAX_CALENDAR_SLOTS: i64 = 2000;
+
+/// Longest a single `poll_changes` call may block waiting for a change, so
+/// one poll cannot pin a listener connection indefinitely.
+pub const MAX_POLL_WAIT_SECS: i64 = 60;
+
+/// How often a watch task re-reads the change log while a notification gap
+/// is outstanding, so an aborted transaction (which never notifies) cannot
+/// stall the committed changes behind it.
+const GAP_RETRY_INTERVAL: std::time::Duration = std::time::Duration::from_millis(500);
+
+/// Most changes a single catch-up read returns, so a stale cursor pages
+/// through the backlog in bounded batches instead of materializing the
+/// whole change log into one query result (and, for `poll_changes`, one
+/// unary response that could exceed the transport's message cap).
+pub const MAX_CHANGES_PER_POLL: i64 = 512;
+
+/// Migrations embedded from `./migrations` at compile time, so a binary can
+/// bring a fresh database to the expected schema without out-of-band tooling.
+pub static MIGRATOR: sqlx::migrate::Migrator = sqlx::migrate!("./migrations");
+
+/// Policy and connection configuration for the Postgres store.
+#[derive(Debug, Clone)]
+pub struct StoreConfig {
+    /// Postgres schema holding the reservation tables, one per tenant. It is
+    /// applied through `search_path`, so every query and migration targets
+    /// this schema; pools handed to [`PgStore::new`] directly must have an
+    /// equivalent `search_path` themselves.
+    pub schema: String,
+    /// Longest span a single reservation may cover, `None` means unlimited.
+    pub max_duration: Option<chrono::Duration>,
+    /// Cap on non-cancelled reservations a single user may hold at once,
+    /// `None` means unlimited.
+    pub max_active_per_user: Option<u32>,
+    /// Upper bound on the number of pooled connections.
+    pub max_connections: u32,
+    /// How long to wait for a free connection before giving up.
+    pub acquire_timeout: Duration,
+    /// Close connections idle for this long, `None` keeps them forever.
+    pub idle_timeout: Option<Duration>,
+    /// Per-statement timeout applied on every connection, so a runaway query
+    /// cannot hold a connection forever. `None` means no limit.
+    pub statement_timeout: Option<Duration>,
+    /// Apply pending embedded migrations while connecting.
+    pub run_migrations: bool,
+    /// How many times to retry a transaction that failed with a transient
+    /// serialization or deadlock error (SQLSTATE 40001/40P01).
+    pub max_retries: u32,
+    /// Base delay for the exponential backoff between retries.
+    pub retry_base_delay: Duration,
+    /// How often the sweeper started by [`PgStore::start_sweeper`] releases
+    /// expired holds.
+    pub hold_sweep_interval: Duration,
+    /// How often the task started by [`PgStore::start_metrics`] refreshes
+    /// the active-reservations gauge.
+    pub metrics_refresh_interval: Duration,
+}
+
+// the connection defaults mirror what PgPool::connect would pick on its own
+impl Default for StoreConfig {
+    fn default() -> Self {
+        Self {
+            schema: "rsvp".to_string(),
+            max_duration: None,
+            max_active_per_user: None,
+            max_connections: 10,
+            acquire_timeout: Duration::from_secs(30),
+            idle_timeout: Some(Duration::from_secs(600)),
+            statement_timeout: None,
+            run_migrations: false,
+            max_retries: 3,
+            retry_base_delay: Duration::from_millis(10),
+            hold_sweep_interval: Duration::from_secs(60),
+            metrics_refresh_interval: Duration::from_secs(60),
+        }
+    }
+}
+
+/// Postgres backed implementation of `ReservationManager`.
+#[derive(Debug, Clone)]
+pub struct PgStore {
+    pool: PgPool,
+    config: StoreConfig,
+    sink: Option<Arc<dyn EventSink>>,
+}
+
+/// Enforce the per-resource overlap capacity inside the caller's
+/// transaction: count the active reservations overlapping `timespan` and
+/// reject once the resource's capacity is reached. Resources without a row
+/// in `resources` are exclusive (capacity 1). The transaction-scoped
+/// advisory lock serializes reserves on the same resource, closing the
+/// count-then-insert race; pass `exclude` when re-checking a row that is
+/// being moved so it does not count against itself.
+async fn check_capacity(
+    conn: &mut PgConnection,
+    resource_id: &str,
+    timespan: &PgRange<DateTime<Utc>>,
+    exclude: Option<Uuid>,
+) -> Result<(), Error> {
+    sqlx::query("SELECT pg_advisory_xact_lock(hashtext($1))")
+        .bind(resource_id)
+        .execute(&mut *conn)
+        .await?;
+    let capacity: i32 =
+        sqlx::query_scalar("SELECT COALESCE((SELECT capacity FROM resources WHERE id = $1), 1)")
+            .bind(resource_id)
+            .fetch_one(&mut *conn)
+            .await?;
+    let overlapping: i64 = sqlx::query_scalar(
+        "SELECT count(*) FROM reservations \
+         WHERE resource_id = $1 AND timespan && $2 \
+           AND status <> 'cancelled' AND archived_at IS NULL \
+           AND id IS DISTINCT FROM $3",
+    )
+    .bind(resource_id)
+    .bind(timespan.clone())
+    .bind(exclude)
+    .fetch_one(&mut *conn)
+    .await?;
+    if overlapping >= capacity as i64 {
+        return Err(Error::ConflictReservation(
+            ReservationConflictInfo::Unparsed(format!(
+                "resource {resource_id} is at capacity {capacity} for the requested window"
+            )),
+        ));
+    }
+    Ok(())
+}
+
+/// Insert one reservation on the given connection, so single, batch and
+/// hold inserts share the same statement. `expires_at` is only set for
+/// holds; `None` means the reservation never expires. The caller must be
+/// inside a transaction: the capacity check's advisory lock and count only
+/// hold together until its commit.
+async fn insert_reservation(
+    conn: &mut PgConnection,
+    rsvp: Reservation,
+    expires_at: Option<DateTime<Utc>>,
+) -> Result<Reservation, Error> {
+    let status = ReservationStatus::try_from(rsvp.status).unwrap_or(ReservationStatus::Unknown);
+    let status = if status == ReservationStatus::Unknown {
+        ReservationStatus::Pending
+    } else {
+        status
+    };
+    let timespan = rsvp.get_timespan()?;
+    // cancelled rows never consume capacity
+    if status != ReservationStatus::Cancelled {
+        check_capacity(conn, &rsvp.resource_id, &timespan, None).await?;
+    }
+
+    let sql = format!(
+        "INSERT INTO reservations (user_id, resource_id, timespan, status, note, expires_at) \
+         VALUES ($1, $2, $3, $4, $5, $6) RETURNING {}",
+        RESERVATION_COLUMNS
+    );
+    let rsvp: Reservation = sqlx::query_as(&sql)
+        .bind(rsvp.user_id)
+        .bind(rsvp.resource_id)
+        .bind(timespan)
+        .bind(RsvpStatus::from(status))
+        .bind(rsvp.note)
+        .bind(expires_at)
+        .fetch_one(conn)
+        .await?;
+    Ok(rsvp)
+}
+
+/// Insert a reservation carrying an idempotency key. `None` means another
+/// row already holds the key; the caller re-selects it.
+async fn insert_with_key(
+    conn: &mut PgConnection,
+    rsvp: Reservation,
+    expires_at: Option<DateTime<Utc>>,
+    idempotency_key: &str,
+) -> Result<Option<Reservation>, Error> {
+    let status = ReservationStatus::try_from(rsvp.status).unwrap_or(ReservationStatus::Unknown);
+    let status = if status == ReservationStatus::Unknown {
+        ReservationStatus::Pending
+    } else {
+        status
+    };
+    let timespan = rsvp.get_timespan()?;
+    if status != ReservationStatus::Cancelled {
+        check_capacity(conn, &rsvp.resource_id, &timespan, None).await?;
+    }
+
+    let sql = format!(
+        "INSERT INTO reservations \
+         (user_id, resource_id, timespan, status, note, expires_at, idempotency_key) \
+         VALUES ($1, $2, $3, $4, $5, $6, $7) \
+         ON CONFLICT (idempotency_key) WHERE idempotency_key IS NOT NULL DO NOTHING \
+         RETURNING {}",
+        RESERVATION_COLUMNS
+    );
+    let rsvp: Option<Reservation> = sqlx::query_as(&sql)
+        .bind(rsvp.user_id)
+        .bind(rsvp.resource_id)
+        .bind(timespan)
+        .bind(RsvpStatus::from(status))
+        .bind(rsvp.note)
+        .bind(expires_at)
+        .bind(idempotency_key)
+        .fetch_optional(conn)
+        .await?;
+    Ok(rsvp)
+}
+
+/// Look up the reservation a previous attempt with the same key created.
+async fn find_by_key(
+    conn: &mut PgConnection,
+    idempotency_key: &str,
+) -> Result<Option<Reservation>, Error> {
+    let sql = format!(
+        "SELECT {} FROM reservations WHERE idempotency_key = $1",
+        RESERVATION_COLUMNS
+    );
+    let rsvp: Option<Reservation> = sqlx::query_as(&sql)
+        .bind(idempotency_key)
+        .fetch_optional(conn)
+        .await?;
+    Ok(rsvp)
+}
+
+impl PgStore {
+    pub fn new(pool: PgPool) -> Self {
+        Self::with_config(pool, StoreConfig::default())
+    }
+
+    pub fn with_config(pool: PgPool, config: StoreConfig) -> Self {
+        Self {
+            pool,
+            config,
+            sink: None,
+        }
+    }
+
+    /// Attach an event sink; events fire after each mutating operation
+    /// commits.
+    pub fn with_sink(mut self, sink: Arc<dyn EventSink>) -> Self {
+        self.sink = Some(sink);
+        self
+    }
+
+    pub async fn from_url(url: &str) -> Result<Self, Error> {
+        Self::from_config(url, StoreConfig::default()).await
+    }
+
+    /// Connect with explicit pool and policy settings.
+    pub async fn from_config(url: &str, config: StoreConfig) -> Result<Self, Error> {
+        validate_schema_name(&config.schema)?;
+        let connect = url.parse::<PgConnectOptions>()?;
+        // the tenant schema comes first so unqualified names resolve there;
+        // public stays on the path for the shared extensions
+        let mut options = vec![("search_path", format!("{},public", config.schema))];
+        if let Some(timeout) = config.statement_timeout {
+            options.push(("statement_timeout", timeout.as_millis().to_string()));
+        }
+        let connect = connect.options(options);
+        let pool = PgPoolOptions::new()
+            .max_connections(config.max_connections)
+            .acquire_timeout(config.acquire_timeout)
+            .idle_timeout(config.idle_timeout)
+            .connect_with(connect)
+            .await?;
+        let store = Self::with_config(pool, config);
+        if store.config.run_migrations {
+            store.migrate().await?;
+        }
+        Ok(store)
+    }
+
+    /// Apply any pending embedded migrations to the configured schema,
+    /// creating the schema first if needed. The migrations use unqualified
+    /// names throughout, so they land wherever `search_path` points.
+    pub async fn migrate(&self) -> Result<(), Error> {
+        validate_schema_name(&self.config.schema)?;
+        sqlx::query(&format!(
+            "CREATE SCHEMA IF NOT EXISTS {}",
+            self.config.schema
+        ))
+        .execute(&self.pool)
+        .await?;
+        MIGRATOR
+            .run(&self.pool)
+            .await
+            .map_err(|e| Error::DbError(sqlx::Error::Migrate(Box::new(e))))
+    }
+
+    /// Report the versions of embedded migrations that have not been applied
+    /// yet, without applying anything. Empty means the schema is current; on
+    /// a fresh database every migration is pending.
+    pub async fn check_migrations(&self) -> Result<Vec<i64>, Error> {
+        let applied: Vec<i64> =
+            match sqlx::query_scalar("SELECT version FROM _sqlx_migrations ORDER BY version")
+                .fetch_all(&self.pool)
+                .await
+            {
+                Ok(applied) => applied,
+                // undefined_table: the bookkeeping table does not exist yet,
+                // which is exactly the fresh-database case; anything else
+                // (unreachable database, bad credentials, ...) is a real
+                // error and must not be reported as "all pending"
+                Err(sqlx::Error::Database(e)) if e.code().as_deref() == Some("42P01") => Vec::new(),
+                Err(e) => return Err(e.into()),
+            };
+        Ok(MIGRATOR
+            .iter()
+            .filter(|m| !applied.contains(&m.version))
+            .map(|m| m.version)
+            .collect())
+    }
+
+    pub fn pool(&self) -> &PgPool {
+        &self.pool
+    }
+
+    /// Spawn the background task that releases expired holds every
+    /// `hold_sweep_interval`. Each pass is a single set-based DELETE, so a
+    /// large backlog of expired holds costs one statement, and the change-log
+    /// trigger turns the deletions into watch events. Abort the returned
+    /// handle to stop sweeping.
+    pub fn start_sweeper(&self) -> tokio::task::JoinHandle<()> {
+        let pool = self.pool.clone();
+        let mut interval = tokio::time::interval(self.config.hold_sweep_interval);
+        tokio::spawn(async move {
+            loop {
+                interval.tick().await;
+                // a failed pass (e.g. the database is briefly down) is
+                // retried on the next tick, so errors are deliberately
+                // swallowed here
+                let _ = sqlx::query(
+                    "DELETE FROM reservations \
+                     WHERE status = 'pending' AND expires_at < now()",
+                )
+                .execute(&pool)
+                .await;
+            }
+        })
+    }
+
+    /// Spawn a task that periodically refreshes the active-reservations
+    /// gauge from the database; counting on a schedule keeps the gauge
+    /// drift-free without taxing the write path. Abort the handle to stop.
+    pub fn start_metrics(&self) -> tokio::task::JoinHandle<()> {
+        let pool = self.pool.clone();
+        let mut interval = tokio::time::interval(self.config.metrics_refresh_interval);
+        tokio::spawn(async move {
+            loop {
+                interval.tick().await;
+                // same stance as the sweeper: a failed pass just waits for
+                // the next tick
+                if let Ok(count) = sqlx::query_scalar::<_, i64>(
+                    "SELECT count(*) FROM reservations \
+                     WHERE status IN ('pending', 'confirmed')",
+                )
+                .fetch_one(&pool)
+                .await
+                {
+                    crate::metrics::set_active(count);
+                }
+            }
+        })
+    }
+
+    /// Set how many overlapping reservations `resource_id` may hold at once.
+    /// Resources without an explicit capacity are exclusive (capacity 1).
+    /// Raising capacity takes effect on the next reserve; lowering it never
+    /// cancels existing reservations, it only stops new ones from booking
+    /// until the count drops below the new limit.
+    pub async fn set_capacity(&self, resource_id: &str, capacity: i32) -> Result<(), Error> {
+        if resource_id.is_empty() {
+            return Err(Error::InvalidResourceId(resource_id.to_string()));
+        }
+        if capacity < 1 {
+            return Err(Error::InvalidField(format!("capacity: {capacity}")));
+        }
+        sqlx::query(
+            "INSERT INTO resources (id, capacity) VALUES ($1, $2) \
+             ON CONFLICT (id) DO UPDATE SET capacity = EXCLUDED.capacity",
+        )
+        .bind(resource_id)
+        .bind(capacity)
+        .execute(&self.pool)
+        .await?;
+        Ok(())
+    }
+
+    /// Run `op`, retrying transient serialization/deadlock failures with
+    /// exponential backoff and jitter. Any other error surfaces immediately.
+    /// Records the total database time as `db_ms` on the surrounding span.
+    async fn retry<T, F, Fut>(&self, op: F) -> Result<T, Error>
+    where
+        F: Fn() -> Fut,
+        Fut: Future<Output = Result<T, Error>>,
+    {
+        let started = std::time::Instant::now();
+        let mut attempt = 0;
+        let result = loop {
+            match op().await {
+                Err(e) if is_retryable(&e) && attempt < self.config.max_retries => {
+                    attempt += 1;
+                    let backoff = self.config.retry_base_delay * 2u32.pow(attempt - 1);
+                    // jitter in [backoff/2, backoff] spreads out contending callers
+                    let delay = backoff.mul_f64(0.5 + rand::random::<f64>() / 2.0);
+                    tokio::time::sleep(delay).await;
+                }
+                result => break result,
+            }
+        };
+        tracing::Span::current().record("db_ms", started.elapsed().as_millis() as u64);
+        if let Err(Error::ConflictReservation(info)) = &result {
+            tracing::warn!(conflict = %info, "reservation conflict");
+        }
+        result
+    }
+
+    /// Run `op` through [`Self::retry`] and record its outcome and latency
+    /// under `name`; see [`crate::metrics`] for the metric names.
+    async fn measured<T, F, Fut>(&self, name: &'static str, op: F) -> Result<T, Error>
+    where
+        F: Fn() -> Fut,
+        Fut: Future<Output = Result<T, Error>>,
+    {
+        let started = std::time::Instant::now();
+        let result = self.retry(op).await;
+        crate::metrics::observe(name, started.elapsed(), &result);
+        result
+    }
+
+    /// Publish an event for a committed mutation, if a sink is attached.
+    async fn emit(&self, change_type: ReservationChangeType, rsvp: &Reservation) {
+        if let Some(sink) = &self.sink {
+            sink.publish(ReservationEvent {
+                change_type,
+                reservation: rsvp.clone(),
+            })
+            .await;
+        }
+    }
+
+    /// The transactional part of `reserve`: quota check and insert commit or
+    /// roll back together, so two concurrent reserves can't both slip under
+    /// the cap.
+    async fn reserve_tx(
+        &self,
+        rsvp: &Reservation,
+        expires_at: Option<DateTime<Utc>>,
+        idempotency_key: &str,
+    ) -> Result<Reservation, Error> {
+        let mut tx = self.pool.begin().await?;
+        // a retried request returns the original row before the quota check,
+        // so a user at their cap can still safely retry
+        if !idempotency_key.is_empty() {
+            if let Some(existing) = find_by_key(&mut tx, idempotency_key).await? {
+                tx.rollback().await?;
+                return Ok(existing);
+            }
+        }
+        self.check_quota(&mut tx, &rsvp.user_id, 1).await?;
+        let rsvp = if idempotency_key.is_empty() {
+            insert_reservation(&mut tx, rsvp.clone(), expires_at).await?
+        } else {
+            // ON CONFLICT DO NOTHING: of two concurrent retries exactly one
+            // inserts; the loser re-reads the winner's row after it commits
+            match insert_with_key(&mut tx, rsvp.clone(), expires_at, idempotency_key).await? {
+                Some(rsvp) => rsvp,
+                None => find_by_key(&mut tx, idempotency_key)
+                    .await?
+                    .ok_or(Error::Unknown)?,
+            }
+        };
+        tx.commit().await?;
+        Ok(rsvp)
+    }
+
+    /// Enforce the per-user active-reservation cap inside the caller's
+    /// transaction, where `reserving` rows are about to be inserted for the
+    /// user. The transaction-scoped advisory lock serializes reserves for
+    /// the same user, closing the check-then-insert race.
+    async fn check_quota(
+        &self,
+        tx: &mut PgConnection,
+        user_id: &str,
+        reserving: i64,
+    ) -> Result<(), Error> {
+        let Some(limit) = self.config.max_active_per_user else {
+            return Ok(());
+        };
+        sqlx::query("SELECT pg_advisory_xact_lock(hashtext($1))")
+            .bind(user_id)
+            .execute(&mut *tx)
+            .await?;
+        let active: i64 = sqlx::query_scalar(
+            "SELECT count(*) FROM reservations \
+             WHERE user_id = $1 AND status <> 'cancelled'",
+        )
+        .bind(user_id)
+        .fetch_one(&mut *tx)
+        .await?;
+        if active + reserving > limit as i64 {
+            return Err(Error::QuotaExceeded {
+                user_id: user_id.to_string(),
+                limit,
+            });
+        }
+        Ok(())
+    }
+
+    /// The transactional part of `batch_reserve`: quota checks and inserts
+    /// commit or roll back together, so a batch cannot slip a user past the
+    /// cap that single reserves enforce.
+    async fn batch_insert(&self, infos: &[ReservationInfo]) -> Result<Vec<Reservation>, Error> {
+        let mut tx = self.pool.begin().await?;
+        // check each user's headroom against their share of the whole batch
+        // up front; the BTreeMap takes the per-user advisory locks in a
+        // stable order, so two concurrent batches cannot deadlock on them
+        let mut per_user: BTreeMap<&str, i64> = BTreeMap::new();
+        for info in infos {
+            *per_user.entry(info.user_id.as_str()).or_default() += 1;
+        }
+        for (user_id, reserving) in per_user {
+            self.check_quota(&mut tx, user_id, reserving).await?;
+        }
+        let mut rsvps = Vec::with_capacity(infos.len());
+        for (index, info) in infos.iter().enumerate() {
+            let rsvp = insert_reservation(&mut tx, info.clone().into(), None)
+                .await
+                .map_err(|e| match e {
+                    Error::ConflictReservation(info) => {
+                        Error::ConflictReservation(ReservationConflictInfo::Unparsed(format!(
+                            "reservation at index {index}: {info}"
+                        )))
+                    }
+                    e => e,
+                })?;
+            rsvps.push(rsvp);
+        }
+        tx.commit().await?;
+        Ok(rsvps)
+    }
+
+    /// The transactional part of `update`: lock the row, merge the masked
+    /// fields over it and write the result back.
+    async fn update_tx(
+        &self,
+        id: Uuid,
+        update: &UpdateRequest,
+        fields: &[UpdateField],
+    ) -> Result<Reservation, Error> {
+        let mut tx = self.pool.begin().await?;
+        let sql = format!(
+            "SELECT {} FROM reservations WHERE id = $1 FOR UPDATE",
+            RESERVATION_COLUMNS
+        );
+        let old: Reservation = sqlx::query_as(&sql)
+            .bind(id)
+            .fetch_optional(&mut *tx)
+            .await?
+            .ok_or(Error::NotFound)?;
+
+        // merge the masked fields over the current row so a lone "start" or
+        // "end" keeps the other bound
+        let mut start = old.start.clone();
+        let mut end = old.end.clone();
+        let mut builder = QueryBuilder::new("UPDATE reservations SET ");
+        let mut set = builder.separated(", ");
+        set.push("version = version + 1");
+        for field in fields {
+            match field {
+                UpdateField::Note => {
+                    set.push("note = ").push_bind_unseparated(&update.note);
+                }
+                UpdateField::ResourceId => {
+                    if update.resource_id.is_empty() {
+                        return Err(Error::InvalidResourceId(update.resource_id.clone()));
+                    }
+                    set.push("resource_id = ")
+                        .push_bind_unseparated(&update.resource_id);
+                }
+                UpdateField::Start => start.clone_from(&update.start),
+                UpdateField::End => end.clone_from(&update.end),
+            }
+        }
+        let window_changed =
+            fields.contains(&UpdateField::Start) || fields.contains(&UpdateField::End);
+        if window_changed {
+            let range = validate_range(start.as_ref(), end.as_ref())?;
+            self.check_duration(start.as_ref(), end.as_ref())?;
+            set.push("timespan = ").push_bind_unseparated(range);
+        }
+        // moving the row re-runs the capacity check against its destination,
+        // so conflicting windows still surface as Error::ConflictReservation
+        if window_changed || fields.contains(&UpdateField::ResourceId) {
+            let resource_id = if fields.contains(&UpdateField::ResourceId) {
+                update.resource_id.as_str()
+            } else {
+                old.resource_id.as_str()
+            };
+            let range = validate_range(start.as_ref(), end.as_ref())?;
+            check_capacity(&mut tx, resource_id, &range, Some(id)).await?;
+        }
+        builder.push(" WHERE id = ").push_bind(id);
+        if update.expected_version > 0 {
+            builder
+                .push(" AND version = ")
+                .push_bind(update.expected_version);
+        }
+        builder.push(format!(" RETURNING {}", RESERVATION_COLUMNS));
+
+        // the row exists and is locked, so zero rows can only mean the
+        // version moved on since the client read it
+        let rsvp = builder
+            .build_query_as::<Reservation>()
+            .fetch_optional(&mut *tx)
+            .await?
+            .ok_or(Error::VersionConflict { id: id.to_string() })?;
+        tx.commit().await?;
+        Ok(rsvp)
+    }
+
+    /// The transactional part of `reschedule`: lock the row and move it to
+    /// the new window unless it is already there.
+    async fn reschedule_tx(
+        &self,
+        id: Uuid,
+        new_range: PgRange<DateTime<Utc>>,
+        expected_version: i32,
+    ) -> Result<Reservation, Error> {
+        let mut tx = self.pool.begin().await?;
+        let sql = format!(
+            "SELECT {} FROM reservations WHERE id = $1 FOR UPDATE",
+            RESERVATION_COLUMNS
+        );
+        let old: Reservation = sqlx::query_as(&sql)
+            .bind(id)
+            .fetch_optional(&mut *tx)
+            .await?
+            .ok_or(Error::NotFound)?;
+
+        // a stale version loses even when the window happens to match: the
+        // client was editing an old snapshot either way
+        if expected_version > 0 && old.version != expected_version {
+            return Err(Error::VersionConflict { id: id.to_string() });
+        }
+
+        // same window: nothing to do, the reservation is already where it should be
+        if old.get_timespan()? == new_range {
+            tx.rollback().await?;
+            return Ok(old);
+        }
+
+        // the destination window must still fit under the resource's
+        // capacity; the moving row itself is excluded from the count
+        check_capacity(&mut tx, &old.resource_id, &new_range, Some(id)).await?;
+        let sql = format!(
+            "UPDATE reservations SET timespan = $2, version = version + 1 \
+             WHERE id = $1 RETURNING {}",
+            RESERVATION_COLUMNS
+        );
+        let rsvp: Reservation = sqlx::query_as(&sql)
+            .bind(id)
+            .bind(new_range)
+            .fetch_one(&mut *tx)
+            .await?;
+        tx.commit().await?;
+        Ok(rsvp)
+    }
+
+    /// The transactional part of `split`: shorten the original, then insert
+    /// the second half. Shortening first keeps the halves from ever
+    /// overlapping, so the split never changes the resource's overlap count
+    /// and needs no capacity check.
+    async fn split_tx(
+        &self,
+        id: Uuid,
+        at: DateTime<Utc>,
+    ) -> Result<(Reservation, Reservation), Error> {
+        let mut tx = self.pool.begin().await?;
+        let sql = format!(
+            "SELECT {} FROM reservations WHERE id = $1 FOR UPDATE",
+            RESERVATION_COLUMNS
+        );
+        let original: Reservation = sqlx::query_as(&sql)
+            .bind(id)
+            .fetch_optional(&mut *tx)
+            .await?
+            .ok_or(Error::NotFound)?;
+
+        // the cut must leave something on both sides
+        let start = original
+            .start
+            .as_ref()
+            .map(abi::convert_to_utc_time)
+            .ok_or(Error::InvalidTime)?;
+        let end = original
+            .end
+            .as_ref()
+            .map(abi::convert_to_utc_time)
+            .ok_or(Error::InvalidTime)?;
+        if at <= start || at >= end {
+            return Err(Error::InvalidTime);
+        }
+
+        let sql = format!(
+            "UPDATE reservations SET timespan = $2, version = version + 1 \
+             WHERE id = $1 RETURNING {}",
+            RESERVATION_COLUMNS
+        );
+        let first: Reservation = sqlx::query_as(&sql)
+            .bind(id)
+            .bind(PgRange::from(start..at))
+            .fetch_one(&mut *tx)
+            .await?;
+
+        let status =
+            ReservationStatus::try_from(original.status).unwrap_or(ReservationStatus::Pending);
+        let sql = format!(
+            "INSERT INTO reservations (user_id, resource_id, timespan, status, note) \
+             VALUES ($1, $2, $3, $4, $5) RETURNING {}",
+            RESERVATION_COLUMNS
+        );
+        let second: Reservation = sqlx::query_as(&sql)
+            .bind(original.user_id)
+            .bind(original.resource_id)
+            .bind(PgRange::from(at..end))
+            .bind(RsvpStatus::from(status))
+            .bind(original.note)
+            .fetch_one(&mut *tx)
+            .await?;
+        tx.commit().await?;
+        Ok((first, second))
+    }
+
+    /// The transactional part of `delete_by_query`: one set-based DELETE
+    /// sharing the query/filter WHERE conditions, so "what would be deleted"
+    /// can be previewed with `count` on the same criteria.
+    async fn delete_by_query_tx(&self, query: &ReservationQuery) -> Result<u64, Error> {
+        let mut tx = self.pool.begin().await?;
+        let mut builder = QueryBuilder::new("DELETE FROM reservations WHERE TRUE");
+        push_conditions(
+            &mut builder,
+            &query.user_id,
+            &query.resource_id,
+            &query.resource_ids,
+            query.status,
+            &query.statuses,
+            query.start.as_ref(),
+            query.end.as_ref(),
+            query.include_archived,
+            query.exclude_blocked,
+            &query.note_contains,
+            None,
+            None,
+        )?;
+        let result = builder.build().execute(&mut *tx).await?;
+        tx.commit().await?;
+        Ok(result.rows_affected())
+    }
+
+    /// The transactional part of `merge`: lock both rows, check they belong
+    /// together and touch, then replace them with one spanning row. Two rows
+    /// become one covering the same span, so the resource's overlap count
+    /// never grows and no capacity check is needed.
+    async fn merge_tx(
+        &self,
+        id_a: Uuid,
+        id_b: Uuid,
+    ) -> Result<(Reservation, Vec<Reservation>), Error> {
+        if id_a == id_b {
+            return Err(Error::NotMergeable(
+                "a reservation cannot be merged with itself".to_string(),
+            ));
+        }
+        let mut tx = self.pool.begin().await?;
+        // ORDER BY fixes the lock order, so two concurrent merges over the
+        // same pair cannot deadlock
+        let sql = format!(
+            "SELECT {} FROM reservations WHERE id = ANY($1) ORDER BY id FOR UPDATE",
+            RESERVATION_COLUMNS
+        );
+        let rows: Vec<Reservation> = sqlx::query_as(&sql)
+            .bind(vec![id_a, id_b])
+            .fetch_all(&mut *tx)
+            .await?;
+        if rows.len() != 2 {
+            return Err(Error::NotFound);
+        }
+
+        let bounds = |rsvp: &Reservation| -> Result<(DateTime<Utc>, DateTime<Utc>), Error> {
+            Ok((
+                rsvp.start
+                    .as_ref()
+                    .map(abi::convert_to_utc_time)
+                    .ok_or(Error::InvalidTime)?,
+                rsvp.end
+                    .as_ref()
+                    .map(abi::convert_to_utc_time)
+                    .ok_or(Error::InvalidTime)?,
+            ))
+        };
+        // work in time order: the earlier half donates status and note
+        let (earlier, later) = if bounds(&rows[0])?.0 <= bounds(&rows[1])?.0 {
+            (&rows[0], &rows[1])
+        } else {
+            (&rows[1], &rows[0])
+        };
+        if earlier.resource_id != later.resource_id {
+            return Err(Error::NotMergeable(format!(
+                "{} and {} are for different resources",
+                earlier.id, later.id
+            )));
+        }
+        if earlier.user_id != later.user_id {
+            return Err(Error::NotMergeable(format!(
+                "{} and {} belong to different users",
+                earlier.id, later.id
+            )));
+        }
+        let (start, earlier_end) = bounds(earlier)?;
+        let (later_start, later_end) = bounds(later)?;
+        // [a, b) and [b, c) touch; anything short of that leaves a gap
+        if earlier_end < later_start {
+            return Err(Error::NotMergeable(format!(
+                "{} ends before {} starts",
+                earlier.id, later.id
+            )));
+        }
+        let end = earlier_end.max(later_end);
+
+        sqlx::query("DELETE FROM reservations WHERE id = ANY($1)")
+            .bind(vec![id_a, id_b])
+            .execute(&mut *tx)
+            .await?;
+        let status =
+            ReservationStatus::try_from(earlier.status).unwrap_or(ReservationStatus::Pending);
+        let sql = format!(
+            "INSERT INTO reservations (user_id, resource_id, timespan, status, note) \
+             VALUES ($1, $2, $3, $4, $5) RETURNING {}",
+            RESERVATION_COLUMNS
+        );
+        let merged: Reservation = sqlx::query_as(&sql)
+            .bind(earlier.user_id.clone())
+            .bind(earlier.resource_id.clone())
+            .bind(PgRange::from(start..end))
+            .bind(RsvpStatus::from(status))
+            .bind(earlier.note.clone())
+            .fetch_one(&mut *tx)
+            .await?;
+        tx.commit().await?;
+        Ok((merged, rows))
+    }
+
+    /// Enforce the configured maximum duration on a prospective time window.
+    fn check_duration(
+        &self,
+        start: Option<&prost_types::Timestamp>,
+        end: Option<&prost_types::Timestamp>,
+    ) -> Result<(), Error> {
+        validate_max_duration(start, end, self.config.max_duration)
+    }
+}
+
+#[async_trait]
+impl ReservationManager for PgStore {
+    #[tracing::instrument(skip_all, fields(user_id = %rsvp.user_id, resource_id = %rsvp.resource_id, db_ms = tracing::field::Empty))]
+    async fn reserve(
+        &self,
+        rsvp: Reservation,
+        idempotency_key: &str,
+    ) -> Result<Reservation, Error> {
+        rsvp.validate()
+            .inspect_err(|e| tracing::warn!(error = %e, "validation failed"))?;
+        self.check_duration(rsvp.start.as_ref(), rsvp.end.as_ref())?;
+        let rsvp = self
+            .measured("reserve", || self.reserve_tx(&rsvp, None, idempotency_key))
+            .await?;
+        self.emit(ReservationChangeType::Create, &rsvp).await;
+        Ok(rsvp)
+    }
+
+    #[tracing::instrument(skip_all, fields(resource_id = %resource_id, db_ms = tracing::field::Empty))]
+    async fn block(
+        &self,
+        resource_id: &str,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+        note: &str,
+    ) -> Result<Reservation, Error> {
+        let mut rsvp = Reservation::new_pending(BLOCK_USER_ID, resource_id, start, end, note);
+        rsvp.status = ReservationStatus::Blocked as i32;
+        rsvp.validate()
+            .inspect_err(|e| tracing::warn!(error = %e, "validation failed"))?;
+        self.check_duration(rsvp.start.as_ref(), rsvp.end.as_ref())?;
+        // a block is an ordinary row as far as the capacity check is
+        // concerned, so overlapping user bookings fail the same way
+        let rsvp = self
+            .measured("block", || async {
+                let mut tx = self.pool.begin().await?;
+                let rsvp = insert_reservation(&mut tx, rsvp.clone(), None).await?;
+                tx.commit().await?;
+                Ok(rsvp)
+            })
+            .await?;
+        self.emit(ReservationChangeType::Create, &rsvp).await;
+        Ok(rsvp)
+    }
+
+    #[tracing::instrument(skip_all, fields(user_id = %info.user_id, resource_id = %info.resource_id, db_ms = tracing::field::Empty))]
+    async fn hold(
+        &self,
+        info: ReservationInfo,
+        ttl: chrono::Duration,
+    ) -> Result<Reservation, Error> {
+        if ttl <= chrono::Duration::zero() {
+            return Err(Error::InvalidField("ttl must be positive".to_string()));
+        }
+        let rsvp = Reservation::from(info);
+        rsvp.validate()
+            .inspect_err(|e| tracing::warn!(error = %e, "validation failed"))?;
+        self.check_duration(rsvp.start.as_ref(), rsvp.end.as_ref())?;
+        let expires_at = Utc::now() + ttl;
+        let rsvp = self
+            .measured("hold", || self.reserve_tx(&rsvp, Some(expires_at), ""))
+            .await?;
+        self.emit(ReservationChangeType::Create, &rsvp).await;
+        Ok(rsvp)
+    }
+
+    #[tracing::instrument(skip_all, fields(batch_size = infos.len(), db_ms = tracing::field::Empty))]
+    async fn batch_reserve(&self, infos: Vec<ReservationInfo>) -> Result<Vec<Reservation>, Error> {
+        // fail fast on malformed input before opening the transaction
+        for info in &infos {
+            info.validate()
+                .inspect_err(|e| tracing::warn!(error = %e, "validation failed"))?;
+            self.check_duration(info.start.as_ref(), info.end.as_ref())?;
+        }
+
+        let rsvps = self
+            .measured("batch_reserve", || self.batch_insert(&infos))
+            .await?;
+        for rsvp in &rsvps {
+            self.emit(ReservationChangeType::Create, rsvp).await;
+        }
+        Ok(rsvps)
+    }
+
+    #[tracing::instrument(skip_all, fields(reservation_id = %id))]
+    async fn confirm(&self, id: &str) -> Result<Reservation, Error> {
+        let id = parse_reservation_id(id)?;
+        let started = std::time::Instant::now();
+        let sql = format!(
+            "UPDATE reservations \
+             SET status = 'confirmed', expires_at = NULL, version = version + 1 \
+             WHERE id = $1 AND status = 'pending' RETURNING {}",
+            RESERVATION_COLUMNS
+        );
+        let rsvp: Option<Reservation> = sqlx::query_as(&sql)
+            .bind(id)
+            .fetch_optional(&self.pool)
+            .await?;
+        let result = match rsvp {
+            Some(rsvp) => {
+                self.emit(ReservationChangeType::Update, &rsvp).await;
+                Ok(rsvp)
+            }
+            // not pending: surface the illegal transition, or NotFound
+            None => match self.get(&id.to_string()).await {
+                Ok(current) => {
+                    let from = ReservationStatus::try_from(current.status)
+                        .unwrap_or(ReservationStatus::Unknown);
+                    Err(Error::InvalidStatusTransition {
+                        from,
+                        to: ReservationStatus::Confirmed,
+                    })
+                }
+                Err(e) => Err(e),
+            },
+        };
+        crate::metrics::observe("confirm", started.elapsed(), &result);
+        result
+    }
+
+    #[tracing::instrument(skip_all, fields(reservation_id = %update.id, db_ms = tracing::field::Empty))]
+    async fn update(&self, update: UpdateRequest) -> Result<Reservation, Error> {
+        let id = parse_reservation_id(&update.id)?;
+        let fields = update.masked_fields()?;
+        if fields.is_empty() {
+            return self.get(&update.id).await;
+        }
+
+        let rsvp = self
+            .measured("update", || self.update_tx(id, &update, &fields))
+            .await?;
+        self.emit(ReservationChangeType::Update, &rsvp).await;
+        Ok(rsvp)
+    }
+
+    #[tracing::instrument(skip_all, fields(reservation_id = %id, db_ms = tracing::field::Empty))]
+    async fn reschedule(
+        &self,
+        id: &str,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+        expected_version: i32,
+    ) -> Result<Reservation, Error> {
+        let id = parse_reservation_id(id)?;
+        if start >= end {
+            return Err(Error::InvalidTime);
+        }
+        self.check_duration(
+            Some(&abi::convert_to_timestamp(&start)),
+            Some(&abi::convert_to_timestamp(&end)),
+        )?;
+        let new_range = validate_range(
+            Some(&abi::convert_to_timestamp(&start)),
+            Some(&abi::convert_to_timestamp(&end)),
+        )?;
+
+        let rsvp = self
+            .measured("reschedule", || {
+                self.reschedule_tx(id, new_range.clone(), expected_version)
+            })
+            .await?;
+        self.emit(ReservationChangeType::Update, &rsvp).await;
+        Ok(rsvp)
+    }
+
+    #[tracing::instrument(skip_all, fields(reservation_id = %id, db_ms = tracing::field::Empty))]
+    async fn split(
+        &self,
+        id: &str,
+        at: DateTime<Utc>,
+    ) -> Result<(Reservation, Reservation), Error> {
+        let id = parse_reservation_id(id)?;
+        let (first, second) = self.measured("split", || self.split_tx(id, at)).await?;
+        self.emit(ReservationChangeType::Update, &first).await;
+        self.emit(ReservationChangeType::Create, &second).await;
+        Ok((first, second))
+    }
+
+    #[tracing::instrument(skip_all, fields(id_a = %id_a, id_b = %id_b, db_ms = tracing::field::Empty))]
+    async fn merge(&self, id_a: &str, id_b: &str) -> Result<Reservation, Error> {
+        let id_a = parse_reservation_id(id_a)?;
+        let id_b = parse_reservation_id(id_b)?;
+        let (merged, replaced) = self.measured("merge", || self.merge_tx(id_a, id_b)).await?;
+        for rsvp in &replaced {
+            self.emit(ReservationChangeType::Delete, rsvp).await;
+        }
+        self.emit(ReservationChangeType::Create, &merged).await;
+        Ok(merged)
+    }
+
+    #[tracing::instrument(skip_all, fields(reservation_id = %id))]
+    async fn cancel(&self, id: &str) -> Result<Reservation, Error> {
+        let id = parse_reservation_id(id)?;
+        let started = std::time::Instant::now();
+        // the row is kept for audit; cancelled reservations stop counting
+        // against capacity so the window can be rebooked. The status
+        // condition mirrors `can_transition(_, Cancelled)`.
+        let sql = format!(
+            "UPDATE reservations SET status = 'cancelled', version = version + 1 \
+             WHERE id = $1 AND status IN ('pending', 'confirmed') RETURNING {}",
+            RESERVATION_COLUMNS
+        );
+        let rsvp: Option<Reservation> = sqlx::query_as(&sql)
+            .bind(id)
+            .fetch_optional(&self.pool)
+            .await?;
+        let result = match rsvp {
+            Some(rsvp) => {
+                self.emit(ReservationChangeType::Update, &rsvp).await;
+                Ok(rsvp)
+            }
+            // not cancellable: surface the illegal transition, or NotFound
+            None => match self.get(&id.to_string()).await {
+                Ok(current) => {
+                    let from = ReservationStatus::try_from(current.status)
+                        .unwrap_or(ReservationStatus::Unknown);
+                    Err(Error::InvalidStatusTransition {
+                        from,
+                        to: ReservationStatus::Cancelled,
+                    })
+                }
+                Err(e) => Err(e),
+            },
+        };
+        crate::metrics::observe("cancel", started.elapsed(), &result);
+        result
+    }
+
+    #[tracing::instrument(skip_all, fields(reservation_id = %id))]
+    async fn archive(&self, id: &str) -> Result<Reservation, Error> {
+        let id = parse_reservation_id(id)?;
+        let started = std::time::Instant::now();
+        let sql = format!(
+            "UPDATE reservations SET archived_at = now(), version = version + 1 \
+             WHERE id = $1 AND status = 'cancelled' RETURNING {}",
+            RESERVATION_COLUMNS
+        );
+        let rsvp: Option<Reservation> = sqlx::query_as(&sql)
+            .bind(id)
+            .fetch_optional(&self.pool)
+            .await?;
+        let result = match rsvp {
+            Some(rsvp) => {
+                self.emit(ReservationChangeType::Update, &rsvp).await;
+                Ok(rsvp)
+            }
+            // distinguish "not cancelled" from "does not exist"
+            None => match self.get(&id.to_string()).await {
+                Ok(_) => Err(Error::NotArchivable(id.to_string())),
+                Err(e) => Err(e),
+            },
+        };
+        crate::metrics::observe("archive", started.elapsed(), &result);
+        result
+    }
+
+    #[tracing::instrument(skip_all, fields(reservation_id = %id))]
+    async fn get(&self, id: &str) -> Result<Reservation, Error> {
+        let id = parse_reservation_id(id)?;
+        let sql = format!(
+            "SELECT {} FROM reservations WHERE id = $1",
+            RESERVATION_COLUMNS
+        );
+        let rsvp: Reservation = sqlx::query_as(&sql)
+            .bind(id)
+            .fetch_optional(&self.pool)
+            .await?
+            .ok_or(Error::NotFound)?;
+        Ok(rsvp)
+    }
+
+    async fn query(&self, query: ReservationQuery) -> Result<Vec<Reservation>, Error> {
+        // drive the paged filter until exhaustion; callers that need bounded
+        // memory should page with `filter` themselves
+        let mut filter = ReservationFilter::from(query);
+        let mut rsvps = Vec::new();
+        loop {
+            let mut page = self.filter(filter.clone()).await?;
+            rsvps.append(&mut page.reservations);
+            if page.next_cursor.is_empty() {
+                return Ok(rsvps);
+            }
+            filter.cursor = page.next_cursor;
+        }
+    }
+
+    #[tracing::instrument(skip_all, fields(user_id = %filter.user_id, resource_id = %filter.resource_id))]
+    async fn filter(&self, filter: ReservationFilter) -> Result<FilterResponse, Error> {
+        let page_size = filter.normalized_page_size();
+        let mut builder = QueryBuilder::new(format!(
+            "SELECT {} FROM reservations WHERE TRUE",
+            RESERVATION_COLUMNS
+        ));
+        push_conditions(
+            &mut builder,
+            &filter.user_id,
+            &filter.resource_id,
+            &filter.resource_ids,
+            filter.status,
+            &filter.statuses,
+            filter.start.as_ref(),
+            filter.end.as_ref(),
+            filter.include_archived,
+            filter.exclude_blocked,
+            &filter.note_contains,
+            filter.created_after.as_ref(),
+            filter.updated_after.as_ref(),
+        )?;
+        // keyset pagination: the cursor carries the last row's sort key, and
+        // the (order_by, id) sort key makes the ordering deterministic even
+        // when the sort column has ties. Rows inserted behind the cursor
+        // mid-iteration are skipped; rows are never yielded twice.
+        let column = filter.order_by_column()?;
+        let cmp = if filter.desc { "<" } else { ">" };
+        let dir = if filter.desc { "DESC" } else { "ASC" };
+        if !filter.cursor.is_empty() {
+            if column == "id" {
+                let cursor = parse_reservation_id(&filter.cursor)?;
+                builder.push(format!(" AND id {cmp} ")).push_bind(cursor);
+            } else {
+                // the pair comes from the cursor itself rather than a
+                // re-read of the cursor row: that row may be gone by now
+                // (hold sweeper, merge, delete_by_query), which would turn
+                // the tuple comparison into NULL and end pagination early
+                let (id, value) = decode_cursor(&filter.cursor)?;
+                builder.push(format!(" AND ({column}, id) {cmp} ("));
+                match column {
+                    "lower(timespan)" => {
+                        let start = DateTime::parse_from_rfc3339(&value)
+                            .map_err(|_| Error::InvalidField(format!("cursor: {}", filter.cursor)))?
+                            .to_utc();
+                        builder.push_bind(start);
+                    }
+                    "status" => {
+                        let status = match value.as_str() {
+                            "unknown" => RsvpStatus::Unknown,
+                            "pending" => RsvpStatus::Pending,
+                            "confirmed" => RsvpStatus::Confirmed,
+                            "blocked" => RsvpStatus::Blocked,
+                            "cancelled" => RsvpStatus::Cancelled,
+                            _ => {
+                                return Err(Error::InvalidField(format!(
+                                    "cursor: {}",
+                                    filter.cursor
+                                )))
+                            }
+                        };
+                        builder.push_bind(status);
+                    }
+                    _ => {
+                        builder.push_bind(value);
+                    }
+                }
+                builder.push(", ").push_bind(id).push(")");
+            }
+        }
+        if column == "id" {
+            builder.push(format!(" ORDER BY id {dir} LIMIT "));
+        } else {
+            builder.push(format!(" ORDER BY {column} {dir}, id {dir} LIMIT "));
+        }
+        builder.push_bind(page_size + 1);
+
+        let mut rsvps = builder
+            .build_query_as::<Reservation>()
+            .fetch_all(&self.pool)
+            .await?;
+        let next_cursor = if rsvps.len() > page_size as usize {
+            rsvps.truncate(page_size as usize);
+            rsvps
+                .last()
+                .map(|r| encode_cursor(column, r))
+                .unwrap_or_default()
+        } else {
+            String::new()
+        };
+        Ok(FilterResponse {
+            reservations: rsvps,
+            next_cursor,
+        })
+    }
+
+    async fn next_reservations(
+        &self,
+        resource_id: &str,
+        now: DateTime<Utc>,
+        limit: i64,
+    ) -> Result<Vec<Reservation>, Error> {
+        if resource_id.is_empty() {
+            return Err(Error::InvalidResourceId(resource_id.to_string()));
+        }
+        if limit <= 0 {
+            return Err(Error::InvalidField(format!("limit: {limit}")));
+        }
+        // the predicate matches reservations_resource_active_start_idx (a
+        // partial index over non-cancelled rows, ordered by start), so this
+        // is O(log n + limit) regardless of how much history the resource has
+        let sql = format!(
+            "SELECT {} FROM reservations \
+             WHERE resource_id = $1 AND status <> 'cancelled' AND lower(timespan) > $2 \
+             ORDER BY lower(timespan) LIMIT $3",
+            RESERVATION_COLUMNS
+        );
+        let rsvps: Vec<Reservation> = sqlx::query_as(&sql)
+            .bind(resource_id)
+            .bind(now)
+            .bind(limit)
+            .fetch_all(&self.pool)
+            .await?;
+        Ok(rsvps)
+    }
+
+    async fn count(&self, query: ReservationQuery) -> Result<i64, Error> {
+        // the predicates come from the same builder as query/filter, so the
+        // count can never drift from what a fetch would return
+        let mut builder = QueryBuilder::new("SELECT count(*) FROM reservations WHERE TRUE");
+        push_conditions(
+            &mut builder,
+            &query.user_id,
+            &query.resource_id,
+            &query.resource_ids,
+            query.status,
+            &query.statuses,
+            query.start.as_ref(),
+            query.end.as_ref(),
+            query.include_archived,
+            query.exclude_blocked,
+            &query.note_contains,
+            // audit-time filters live on ReservationFilter only
+            None,
+            None,
+        )?;
+        let count: i64 = builder.build_query_scalar().fetch_one(&self.pool).await?;
+        Ok(count)
+    }
+
+    async fn aggregate_by_resource(
+        &self,
+        query: ReservationQuery,
+        top_n: i32,
+    ) -> Result<Vec<(String, i64)>, Error> {
+        // same predicate builder as query/filter/count, grouped in the
+        // database so only one row per resource crosses the wire
+        let mut builder =
+            QueryBuilder::new("SELECT resource_id, count(*) AS count FROM reservations WHERE TRUE");
+        push_conditions(
+            &mut builder,
+            &query.user_id,
+            &query.resource_id,
+            &query.resource_ids,
+            query.status,
+            &query.statuses,
+            query.start.as_ref(),
+            query.end.as_ref(),
+            query.include_archived,
+            query.exclude_blocked,
+            &query.note_contains,
+            // audit-time filters live on ReservationFilter only
+            None,
+            None,
+        )?;
+        // resource_id breaks count ties so the order is deterministic
+        builder.push(" GROUP BY resource_id ORDER BY count DESC, resource_id");
+        if top_n > 0 {
+            builder.push(" LIMIT ").push_bind(top_n as i64);
+        }
+        let rows: Vec<(String, i64)> = builder.build_query_as().fetch_all(&self.pool).await?;
+        Ok(rows)
+    }
+
+    #[tracing::instrument(skip_all, fields(resource_id = %resource_id))]
+    async fn calendar(
+        &self,
+        resource_id: &str,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+        slot: chrono::Duration,
+    ) -> Result<Vec<CalendarSlot>, Error> {
+        if resource_id.is_empty() {
+            return Err(Error::InvalidResourceId(resource_id.to_string()));
+        }
+        if start >= end {
+            return Err(Error::InvalidTime);
+        }
+        // the slot is carried into SQL in whole seconds, so a sub-second
+        // slot is rejected rather than truncated to zero (which would also
+        // divide by zero below)
+        if slot < chrono::Duration::seconds(1) {
+            return Err(Error::InvalidField(
+                "slot must be at least one second".to_string(),
+            ));
+        }
+        let slots = (end - start).num_seconds() / slot.num_seconds();
+        if slots > MAX_CALENDAR_SLOTS {
+            return Err(Error::InvalidField(format!(
+                "range would generate {slots} slots, the maximum is {MAX_CALENDAR_SLOTS}"
+            )));
+        }
+
+        // one set-based pass: generate the slot starts, left-join every
+        // active reservation touching each slot (partial overlap counts) and
+        // fold the join into two flags per slot
+        let sql = "SELECT s.slot_start,                    count(r.id) > 0 AS occupied,                    COALESCE(bool_or(r.status = 'blocked'), false) AS blocked                    FROM generate_series(                        $2::timestamptz,                        $3::timestamptz - make_interval(secs => $4),                        make_interval(secs => $4)                    ) AS s(slot_start)                    LEFT JOIN reservations r                      ON r.resource_id = $1                     AND r.status <> 'cancelled'                     AND r.timespan && tstzrange(s.slot_start, s.slot_start + make_interval(secs => $4), '[)')                    GROUP BY s.slot_start ORDER BY s.slot_start";
+        let rows: Vec<(DateTime<Utc>, bool, bool)> = sqlx::query_as(sql)
+            .bind(resource_id)
+            .bind(start)
+            .bind(end)
+            .bind(slot.num_seconds() as f64)
+            .fetch_all(&self.pool)
+            .await?;
+
+        Ok(rows
+            .into_iter()
+            .map(|(slot_start, occupied, blocked)| {
+                let status = match (blocked, occupied) {
+                    (true, _) => SlotStatus::Blocked,
+                    (false, true) => SlotStatus::Reserved,
+                    (false, false) => SlotStatus::Free,
+                };
+                CalendarSlot {
+                    start: Some(abi::convert_to_timestamp(&slot_start)),
+                    end: Some(abi::convert_to_timestamp(&(slot_start + slot))),
+                    status: status as i32,
+                }
+            })
+            .collect())
+    }
+
+    #[tracing::instrument(skip_all, fields(resource_id = %resource_id))]
+    async fn check_availability(
+        &self,
+        resource_id: &str,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+    ) -> Result<Vec<String>, Error> {
+        if resource_id.is_empty() {
+            return Err(Error::InvalidResourceId(resource_id.to_string()));
+        }
+        let range = validate_range(
+            Some(&abi::convert_to_timestamp(&start)),
+            Some(&abi::convert_to_timestamp(&end)),
+        )?;
+
+        // read-only transaction: this must never mutate state. The predicate
+        // mirrors the capacity check (half-open overlap, cancelled rows
+        // exempt) so an empty answer means the insert would have succeeded
+        // now on an exclusive resource.
+        let mut tx = self.pool.begin().await?;
+        sqlx::query("SET TRANSACTION READ ONLY")
+            .execute(&mut *tx)
+            .await?;
+        let ids: Vec<Uuid> = sqlx::query_scalar(
+            "SELECT id FROM reservations \
+             WHERE resource_id = $1 AND timespan && $2 AND status <> 'cancelled' \
+             ORDER BY lower(timespan)",
+        )
+        .bind(resource_id)
+        .bind(range)
+        .fetch_all(&mut *tx)
+        .await?;
+        tx.rollback().await?;
+        Ok(ids.into_iter().map(|id| id.to_string()).collect())
+    }
+
+    #[tracing::instrument(skip_all, fields(allow_overlaps))]
+    async fn bulk_import(
+        &self,
+        mut infos: mpsc::Receiver<ReservationInfo>,
+        allow_overlaps: bool,
+    ) -> Result<BulkImportResponse, Error> {
+        let mut tx = self.pool.begin().await?;
+        let mut copy = (*tx)
+            .copy_in_raw(
+                "COPY reservations (user_id, resource_id, timespan, status, note) \
+                 FROM STDIN WITH (FORMAT text)",
+            )
+            .await?;
+        let mut skipped = 0;
+        let mut first_error = String::new();
+        let mut touched: BTreeSet<String> = BTreeSet::new();
+        while let Some(info) = infos.recv().await {
+            match copy_row(&info) {
+                Ok(line) => {
+                    touched.insert(info.resource_id);
+                    copy.send(line.into_bytes()).await?;
+                }
+                Err(e) => {
+                    skipped += 1;
+                    if first_error.is_empty() {
+                        first_error = e.to_string();
+                    }
+                }
+            }
+        }
+        let inserted = copy.finish().await? as i64;
+
+        if !allow_overlaps {
+            // COPY skips the per-insert capacity check, so run one batch-wide
+            // pass before commit. Locking the touched resources in sorted
+            // order keeps concurrent imports and reserves from deadlocking.
+            for resource_id in &touched {
+                sqlx::query("SELECT pg_advisory_xact_lock(hashtext($1))")
+                    .bind(resource_id)
+                    .execute(&mut *tx)
+                    .await?;
+            }
+            let over: Option<String> = sqlx::query_scalar(
+                "SELECT r.resource_id FROM reservations r \
+                 WHERE r.resource_id = ANY($1) \
+                   AND r.status <> 'cancelled' AND r.archived_at IS NULL \
+                   AND (SELECT count(*) FROM reservations o \
+                        WHERE o.resource_id = r.resource_id AND o.timespan && r.timespan \
+                          AND o.status <> 'cancelled' AND o.archived_at IS NULL) \
+                       > COALESCE((SELECT capacity FROM resources WHERE id = r.resource_id), 1) \
+                 LIMIT 1",
+            )
+            .bind(touched.iter().cloned().collect::<Vec<_>>())
+            .fetch_optional(&mut *tx)
+            .await?;
+            if let Some(resource_id) = over {
+                return Err(Error::ConflictReservation(
+                    ReservationConflictInfo::Unparsed(format!(
+                        "bulk import would put resource {resource_id} over capacity"
+                    )),
+                ));
+            }
+        }
+        tx.commit().await?;
+
+        Ok(BulkImportResponse {
+            inserted,
+            skipped,
+            first_error,
+        })
+    }
+
+    #[tracing::instrument(skip_all, fields(confirm_delete_all, db_ms = tracing::field::Empty))]
+    async fn delete_by_query(
+        &self,
+        query: ReservationQuery,
+        confirm_delete_all: bool,
+    ) -> Result<u64, Error> {
+        // include_archived widens the match rather than narrowing it, so it
+        // does not count as a safeguard against deleting everything
+        let narrows = !query.user_id.is_empty()
+            || !query.resource_id.is_empty()
+            || !query.resource_ids.is_empty()
+            || query.status != ReservationStatus::Unknown as i32
+            || !query.statuses.is_empty()
+            || query.start.is_some()
+            || query.end.is_some()
+            || !query.note_contains.is_empty()
+            || query.exclude_blocked;
+        if !narrows && !confirm_delete_all {
+            return Err(Error::InvalidField(
+                "query matches every reservation; set confirm_delete_all to delete them all"
+                    .to_string(),
+            ));
+        }
+        self.measured("delete_by_query", || self.delete_by_query_tx(&query))
+            .await
+    }
+
+    async fn export(
+        &self,
+        query: ReservationQuery,
+        format: ExportFormat,
+    ) -> Result<mpsc::Receiver<Result<String, Error>>, Error> {
+        let this = self.clone();
+        let (tx, rx) = mpsc::channel(128);
+        tokio::spawn(async move {
+            let mut exporter = Exporter::new(format);
+            let mut filter = ReservationFilter::from(query);
+            loop {
+                match this.filter(filter.clone()).await {
+                    Ok(page) => {
+                        for rsvp in &page.reservations {
+                            if tx.send(Ok(exporter.chunk(rsvp))).await.is_err() {
+                                return;
+                            }
+                        }
+                        if page.next_cursor.is_empty() {
+                            break;
+                        }
+                        filter.cursor = page.next_cursor;
+                    }
+                    Err(e) => {
+                        let _ = tx.send(Err(e)).await;
+                        return;
+                    }
+                }
+            }
+            if let Some(tail) = exporter.finish() {
+                let _ = tx.send(Ok(tail)).await;
+            }
+        });
+        Ok(rx)
+    }
+
+    async fn watch(
+        &self,
+        resume_from: i64,
+    ) -> Result<mpsc::Receiver<Result<WatchResponse, Error>>, Error> {
+        // subscribe before reading the change log so nothing falls between
+        // the catch-up query and the first live notification
+        let mut listener = PgListener::connect_with(&self.pool).await?;
+        listener.listen(CHANGE_CHANNEL).await?;
+
+        // resume_from = 0 means "from now": skip the existing backlog. The
+        // fence keeps ids of still-in-flight transactions above the starting
+        // mark, so their changes are delivered once they commit.
+        let last_seen = if resume_from > 0 {
+            resume_from
+        } else {
+            let sql = format!(
+                "SELECT COALESCE(MAX(id), 0) FROM reservation_changes WHERE {}",
+                stable_change_fence("reservation_changes")
+            );
+            sqlx::query_scalar(&sql).fetch_one(&self.pool).await?
+        };
+
+        let pool = self.pool.clone();
+        let (tx, rx) = mpsc::channel(128);
+        tokio::spawn(async move {
+            let mut last_seen = last_seen;
+            // the highest change id any notification has announced; while it
+            // runs ahead of last_seen a gap is outstanding: the missing ids
+            // belong to transactions the catch-up fence is still holding back
+            let mut announced = last_seen;
+            loop {
+                // every pass drains all stable changes past the high-water
+                // mark, so coalesced or duplicate notifications cannot skip
+                // or repeat
+                match forward_changes(&pool, &tx, last_seen).await {
+                    Ok(Some(id)) => last_seen = id,
+                    // the client hung up
+                    Ok(None) => return,
+                    Err(e) => {
+                        let _ = tx.send(Err(e)).await;
+                        return;
+                    }
+                }
+                announced = announced.max(last_seen);
+                if announced > last_seen {
+                    // a gap is outstanding; if the log holds nothing past the
+                    // cursor at all, the announced transaction aborted and
+                    // its id will never appear, so drop the gap instead of
+                    // re-polling forever (a transaction still in flight
+                    // re-announces itself when its commit notifies)
+                    match sqlx::query_scalar::<_, i64>(
+                        "SELECT COALESCE(MAX(id), 0) FROM reservation_changes",
+                    )
+                    .fetch_one(&pool)
+                    .await
+                    {
+                        Ok(max_id) if max_id <= last_seen => announced = last_seen,
+                        Ok(_) => {}
+                        Err(e) => {
+                            let _ = tx.send(Err(e.into())).await;
+                            return;
+                        }
+                    }
+                }
+                // fast path: the trigger serializes the changed row into the
+                // NOTIFY payload, so in-order changes are forwarded without
+                // touching the database
+                loop {
+                    // with a gap outstanding, re-poll the log on a timer too:
+                    // a transaction that aborted never notifies, and its id
+                    // must not stall the committed changes behind it
+                    let notification = if announced > last_seen {
+                        match tokio::time::timeout(GAP_RETRY_INTERVAL, listener.recv()).await {
+                            Ok(received) => received,
+                            Err(_) => break,
+                        }
+                    } else {
+                        listener.recv().await
+                    };
+                    let notification = match notification {
+                        Ok(notification) => notification,
+                        Err(e) => {
+                            let _ = tx.send(Err(e.into())).await;
+                            return;
+                        }
+                    };
+                    match parse_change_payload(notification.payload()) {
+                        // in order and no gap outstanding: forward directly
+                        Some(change)
+                            if change.change_id == last_seen + 1
+                                && announced <= change.change_id =>
+                        {
+                            last_seen = change.change_id;
+                            announced = change.change_id;
+                            if tx.send(Ok(change)).await.is_err() {
+                                return;
+                            }
+                        }
+                        // a duplicate delivery; nothing new to forward
+                        Some(change) if change.change_id <= last_seen => {}
+                        // a gap (commit order differs from id order): record
+                        // how far ahead it reaches and re-sync from the log
+                        Some(change) => {
+                            announced = announced.max(change.change_id);
+                            break;
+                        }
+                        // an oversized payload reduced to a bare id, or an
+                        // unparseable payload: re-sync from the change log
+                        None => break,
+                    }
+                }
+            }
+        });
+        Ok(rx)
+    }
+
+    #[tracing::instrument(skip_all, fields(resume_from))]
+    async fn poll_changes(
+        &self,
+        resume_from: i64,
+        wait: chrono::Duration,
+    ) -> Result<PollChangesResponse, Error> {
+        if wait < chrono::Duration::zero() {
+            return Err(Error::InvalidField("wait must not be negative".to_string()));
+        }
+        let wait = wait.min(chrono::Duration::seconds(MAX_POLL_WAIT_SECS));
+
+        // resume_from = 0 means "from now": skip the existing backlog. The
+        // fence keeps ids of still-in-flight transactions above the starting
+        // cursor, so their changes surface on the next poll once committed;
+        // fetch_changes applies the same fence, so the cursor handed back
+        // never advances past a change that has not committed yet.
+        let last_seen = if resume_from > 0 {
+            resume_from
+        } else {
+            let sql = format!(
+                "SELECT COALESCE(MAX(id), 0) FROM reservation_changes WHERE {}",
+                stable_change_fence("reservation_changes")
+            );
+            sqlx::query_scalar(&sql).fetch_one(&self.pool).await?
+        };
+
+        let changes = fetch_changes(&self.pool, last_seen).await?;
+        if !changes.is_empty() || wait.is_zero() {
+            let next_resume_from = changes.last().map_or(last_seen, |c| c.change_id);
+            return Ok(PollChangesResponse {
+                changes,
+                next_resume_from,
+            });
+        }
+
+        // nothing pending: block on NOTIFY instead of re-querying, so rapid
+        // empty polls cost one query plus a LISTEN, never a query loop
+        let mut listener = PgListener::connect_with(&self.pool).await?;
+        listener.listen(CHANGE_CHANNEL).await?;
+        let deadline = tokio::time::Instant::now() + wait.to_std().unwrap_or_default();
+        loop {
+            // re-check after subscribing (and after every wake-up) so a
+            // change committed in between is never missed
+            let changes = fetch_changes(&self.pool, last_seen).await?;
+            if !changes.is_empty() {
+                let next_resume_from = changes.last().map_or(last_seen, |c| c.change_id);
+                return Ok(PollChangesResponse {
+                    changes,
+                    next_resume_from,
+                });
+            }
+            match tokio::time::timeout_at(deadline, listener.recv()).await {
+                // woken by a change; the fetch above picks it up next pass
+                Ok(Ok(_)) => {}
+                Ok(Err(e)) => return Err(e.into()),
+                // timed out: empty result, cursor unchanged
+                Err(_) => {
+                    return Ok(PollChangesResponse {
+                        changes: Vec::new(),
+                        next_resume_from: last_seen,
+                    })
+                }
+            }
+        }
+    }
+}
+
+/// The JSON the change trigger puts into a NOTIFY payload: the change-log
+/// entry plus the changed row, absent for deletes. Oversized payloads are
+/// reduced to the bare change id by the trigger and fail to parse here,
+/// which routes them through the catch-up query instead.
+#[derive(serde::Deserialize)]
+struct ChangePayload {
+    change_id: i64,
+    op: String,
+    reservation: Option<ChangePayloadRow>,
+}
+
+#[derive(serde::Deserialize)]
+struct ChangePayloadRow {
+    id: String,
+    user_id: String,
+    resource_id: String,
+    start: Option<DateTime<Utc>>,
+    end: Option<DateTime<Utc>>,
+    status: String,
+    note: Option<String>,
+    version: i32,
+    created_at: DateTime<Utc>,
+    updated_at: DateTime<Utc>,
+}
+
+/// Decode a full-row NOTIFY payload; `None` means the payload does not carry
+/// the row (bare-id fallback or a pre-upgrade trigger) and the change log
+/// must be consulted.
+fn parse_change_payload(payload: &str) -> Option<WatchResponse> {
+    let change: ChangePayload = serde_json::from_str(payload).ok()?;
+    let change_type = match change.op.as_str() {
+        "create" => ReservationChangeType::Create,
+        "update" => ReservationChangeType::Update,
+        "delete" => ReservationChangeType::Delete,
+        _ => ReservationChangeType::Unknown,
+    };
+    let reservation = change.reservation.map(|row| {
+        let status = match row.status.as_str() {
+            "pending" => ReservationStatus::Pending,
+            "confirmed" => ReservationStatus::Confirmed,
+            "blocked" => ReservationStatus::Blocked,
+            "cancelled" => ReservationStatus::Cancelled,
+            _ => ReservationStatus::Unknown,
+        };
+        Reservation {
+            id: row.id,
+            user_id: row.user_id,
+            status: status as i32,
+            resource_id: row.resource_id,
+            start: row.start.as_ref().map(abi::convert_to_timestamp),
+            end: row.end.as_ref().map(abi::convert_to_timestamp),
+            note: row.note.unwrap_or_default(),
+            version: row.version,
+            created_at: Some(abi::convert_to_timestamp(&row.created_at)),
+            updated_at: Some(abi::convert_to_timestamp(&row.updated_at)),
+        }
+    });
+    Some(WatchResponse {
+        change_type: change_type as i32,
+        change_id: change.change_id,
+        reservation,
+    })
+}
+
+/// WHERE fragment keeping only change rows of `alias` whose inserting
+/// transaction is older than every transaction still in flight. Change ids
+/// are allocated at insert time but become visible at commit, so commit
+/// order can differ from id order; without this fence a catch-up read could
+/// advance the high-water mark past an id whose transaction has not
+/// committed yet, and that change would be skipped forever. Fenced-out rows
+/// are picked up by a later pass once the change log quiesces past them.
+/// (The xid comparison goes through text because Postgres offers no direct
+/// ordering on the xid type; the log only ever holds recent rows, so epoch
+/// wraparound is not a concern here.)
+fn stable_change_fence(alias: &str) -> String {
+    format!(
+        "{alias}.xmin::text::bigint < \
+         mod(txid_snapshot_xmin(txid_current_snapshot()), 4294967296)"
+    )
+}
+
+/// Fetch up to [`MAX_CHANGES_PER_POLL`] persisted changes with id greater
+/// than `last_seen` that are safe to deliver, oldest first; shared by the
+/// watch stream and `poll_changes`. The cursor protocol supports partial
+/// delivery, so a stale cursor pages through a long backlog in bounded
+/// batches instead of materializing the whole change log at once.
+async fn fetch_changes(pool: &PgPool, last_seen: i64) -> Result<Vec<WatchResponse>, Error> {
+    let sql = format!(
+        "SELECT c.id AS change_id, c.op::text AS op, \
+         r.id, r.user_id, r.resource_id, r.timespan, r.status, r.note, r.version, \
+         r.created_at, r.updated_at \
+         FROM reservation_changes c \
+         LEFT JOIN reservations r ON r.id = c.reservation_id \
+         WHERE c.id > $1 AND {} ORDER BY c.id LIMIT $2",
+        stable_change_fence("c")
+    );
+    let rows = sqlx::query(&sql)
+        .bind(last_seen)
+        .bind(MAX_CHANGES_PER_POLL)
+        .fetch_all(pool)
+        .await?;
+    rows.iter().map(change_from_row).collect()
+}
+
+/// Send every stable change with id greater than `last_seen`, oldest
+/// first, fetching batch by batch so a long backlog never materializes in
+/// memory at once. Returns the new high-water mark, or `None` if the
+/// receiver is gone.
+async fn forward_changes(
+    pool: &PgPool,
+    tx: &mpsc::Sender<Result<WatchResponse, Error>>,
+    mut last_seen: i64,
+) -> Result<Option<i64>, Error> {
+    loop {
+        let changes = fetch_changes(pool, last_seen).await?;
+        let more = changes.len() == MAX_CHANGES_PER_POLL as usize;
+        for change in changes {
+            last_seen = change.change_id;
+            if tx.send(Ok(change)).await.is_err() {
+                return Ok(None);
+            }
+        }
+        if !more {
+            return Ok(Some(last_seen));
+        }
+    }
+}
+
+fn change_from_row(row: &PgRow) -> Result<WatchResponse, Error> {
+    let change_type = match row.get::<&str, _>("op") {
+        "create" => ReservationChangeType::Create,
+        "update" => ReservationChangeType::Update,
+        "delete" => ReservationChangeType::Delete,
+        _ => ReservationChangeType::Unknown,
+    };
+    // the reservation side of the join is gone for deleted rows
+    let reservation = match row.get::<Option<Uuid>, _>("id") {
+        Some(_) => Some(Reservation::from_row(row)?),
+        None => None,
+    };
+    Ok(WatchResponse {
+        change_type: change_type as i32,
+        change_id: row.get("change_id"),
+        reservation,
+    })
+}
+
+/// One reservation as a line of COPY text format: tab-separated columns,
+/// newline-terminated, with the text-format escapes applied.
+fn copy_row(info: &ReservationInfo) -> Result<String, Error> {
+    info.validate()?;
+    let status = ReservationStatus::try_from(info.status).unwrap_or(ReservationStatus::Unknown);
+    let status = if status == ReservationStatus::Unknown {
+        ReservationStatus::Pending
+    } else {
+        status
+    };
+    // text form of the tstzrange the insert path builds via get_timespan
+    let start = info.start.as_ref().map(abi::convert_to_utc_time).unwrap();
+    let end = info.end.as_ref().map(abi::convert_to_utc_time).unwrap();
+    let timespan = format!("[\"{}\",\"{}\")", start.to_rfc3339(), end.to_rfc3339());
+    let status = match RsvpStatus::from(status) {
+        RsvpStatus::Unknown => "unknown",
+        RsvpStatus::Pending => "pending",
+        RsvpStatus::Confirmed => "confirmed",
+        RsvpStatus::Blocked => "blocked",
+        RsvpStatus::Cancelled => "cancelled",
+    };
+    Ok(format!(
+        "{}\t{}\t{}\t{}\t{}\n",
+        copy_escape(&info.user_id),
+        copy_escape(&info.resource_id),
+        timespan,
+        status,
+        copy_escape(&info.note)
+    ))
+}
+
+/// Escape the characters COPY text format treats specially.
+fn copy_escape(field: &str) -> String {
+    let mut out = String::with_capacity(field.len());
+    for c in field.chars() {
+        match c {
+            '\\' => out.push_str("\\\\"),
+            '\t' => out.push_str("\\t"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            c => out.push(c),
+        }
+    }
+    out
+}
+
+/// Transient serialization/deadlock failures (SQLSTATE 40001/40P01) succeed
+/// on retry; everything else, notably capacity conflicts, must pass through
+/// untouched.
+fn is_retryable(e: &Error) -> bool {
+    match e {
+        Error::DbError(sqlx::Error::Database(e)) => {
+            matches!(e.code().as_deref(), Some("40001") | Some("40P01"))
+        }
+        _ => false,
+    }
+}
+
+/// Encode the keyset cursor for a page ending at `last`: the bare id when
+/// ordering by id, otherwise `id:order_value` so the next page is anchored
+/// without re-reading the cursor row, which may have been deleted between
+/// pages.
+fn encode_cursor(column: &str, last: &Reservation) -> String {
+    match column {
+        "id" => last.id.clone(),
+        "lower(timespan)" => {
+            let start = last
+                .start
+                .as_ref()
+                .map(|ts| abi::convert_to_utc_time(ts).to_rfc3339())
+                .unwrap_or_default();
+            format!("{}:{start}", last.id)
+        }
+        "status" => {
+            let status =
+                ReservationStatus::try_from(last.status).unwrap_or(ReservationStatus::Unknown);
+            let status = match RsvpStatus::from(status) {
+                RsvpStatus::Unknown => "unknown",
+                RsvpStatus::Pending => "pending",
+                RsvpStatus::Confirmed => "confirmed",
+                RsvpStatus::Blocked => "blocked",
+                RsvpStatus::Cancelled => "cancelled",
+            };
+            format!("{}:{status}", last.id)
+        }
+        // resource_id; ':' never appears in a uuid, so splitting on the
+        // first ':' recovers the pair even when the value contains one
+        _ => format!("{}:{}", last.id, last.resource_id),
+    }
+}
+
+/// Split an `id:order_value` cursor back into its parts.
+fn decode_cursor(cursor: &str) -> Result<(Uuid, String), Error> {
+    let (id, value) = cursor
+        .split_once(':')
+        .ok_or_else(|| Error::InvalidField(format!("cursor: {cursor}")))?;
+    Ok((parse_reservation_id(id)?, value.to_string()))
+}
+
+/// Push the shared WHERE conditions for query/filter onto the builder.
+#[allow(clippy::too_many_arguments)]
+fn push_conditions(
+    builder: &mut QueryBuilder<'_, sqlx::Postgres>,
+    user_id: &str,
+    resource_id: &str,
+    resource_ids: &[String],
+    status: i32,
+    statuses: &[i32],
+    start: Option<&prost_types::Timestamp>,
+    end: Option<&prost_types::Timestamp>,
+    include_archived: bool,
+    exclude_blocked: bool,
+    note_contains: &str,
+    created_after: Option<&prost_types::Timestamp>,
+    updated_after: Option<&prost_types::Timestamp>,
+) -> Result<(), Error> {
+    if !include_archived {
+        builder.push(" AND archived_at IS NULL");
+    }
+    if exclude_blocked {
+        builder.push(" AND status <> 'blocked'");
+    }
+    if !user_id.is_empty() {
+        builder
+            .push(" AND user_id = ")
+            .push_bind(user_id.to_string());
+    }
+    // a non-empty resource list wins over the single resource_id field
+    if !resource_ids.is_empty() {
+        builder
+            .push(" AND resource_id = ANY(")
+            .push_bind(resource_ids.to_vec())
+            .push(")");
+    } else if !resource_id.is_empty() {
+        builder
+            .push(" AND resource_id = ")
+            .push_bind(resource_id.to_string());
+    }
+    // a non-empty status list wins over the single status field
+    let statuses = statuses_to_db(statuses)?;
+    if !statuses.is_empty() {
+        builder
+            .push(" AND status = ANY(")
+            .push_bind(statuses)
+            .push(")");
+    } else {
+        let status = ReservationStatus::try_from(status).unwrap_or(ReservationStatus::Unknown);
+        if status != ReservationStatus::Unknown {
+            builder
+                .push(" AND status = ")
+                .push_bind(RsvpStatus::from(status));
+        }
+    }
+    if !note_contains.is_empty() {
+        // escaped, so % and _ from the user match literally; backed by the
+        // trigram index on note
+        builder
+            .push(" AND note ILIKE ")
+            .push_bind(format!("%{}%", escape_like(note_contains)));
+    }
+    // either bound may be missing; that side of the range is unbounded
+    if start.is_some() || end.is_some() {
+        let range = query_range(start, end)?;
+        builder.push(" AND timespan && ").push_bind(range);
+    }
+    if let Some(ts) = created_after {
+        builder
+            .push(" AND created_at > ")
+            .push_bind(abi::convert_to_utc_time(ts));
+    }
+    if let Some(ts) = updated_after {
+        builder
+            .push(" AND updated_at > ")
+            .push_bind(abi::convert_to_utc_time(ts));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn embedded_migrations_should_match_the_migrations_directory() {
+        let dir = std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("migrations");
+        let mut on_disk: Vec<i64> = std::fs::read_dir(dir)
+            .unwrap()
+            .map(|entry| {
+                let name = entry.unwrap().file_name();
+                let name = name.to_str().unwrap();
+                name.split('_').next().unwrap().parse().unwrap()
+            })
+            .collect();
+        on_disk.sort_unstable();
+
+        let embedded: Vec<i64> = MIGRATOR.iter().map(|m| m.version).collect();
+        assert_eq!(
+            embedded, on_disk,
+            "rebuild so sqlx::migrate! re-embeds the migrations directory"
+        );
+    }
+
+    #[test]
+    fn multi_resource_queries_should_collapse_into_one_any_predicate() {
+        // three rooms become a single ANY bind; the union keeps the same
+        // deterministic (order_by, id) sort that `filter` appends, since the
+        // predicate does not affect the ordering clause
+        let rooms: Vec<String> = ["room-1", "room-2", "room-3"]
+            .iter()
+            .map(|s| s.to_string())
+            .collect();
+        let mut builder = QueryBuilder::new("SELECT 1 FROM reservations WHERE TRUE");
+        push_conditions(
+            &mut builder,
+            "",
+            "ignored-when-list-is-set",
+            &rooms,
+            0,
+            &[],
+            None,
+            None,
+            false,
+            false,
+            "",
+            None,
+            None,
+        )
+        .unwrap();
+        let sql = builder.sql();
+        assert!(sql.contains("resource_id = ANY("));
+        assert!(!sql.contains("resource_id = $"));
+
+        // the single-resource path still binds a plain equality
+        let mut builder = QueryBuilder::new("SELECT 1 FROM reservations WHERE TRUE");
+        push_conditions(
+            &mut builder,
+            "",
+            "room-1",
+            &[],
+            0,
+            &[],
+            None,
+            None,
+            false,
+            false,
+            "",
+            None,
+            None,
+        )
+        .unwrap();
+        assert!(builder.sql().contains("resource_id = $"));
+    }
+
+    #[test]
+    fn audit_time_filters_should_add_strict_lower_bounds() {
+        let after = abi::convert_to_timestamp(
+            &chrono::DateTime::parse_from_rfc3339("2024-04-01T00:00:00Z")
+                .unwrap()
+                .to_utc(),
+        );
+        let mut builder = QueryBuilder::new("SELECT 1 FROM reservations WHERE TRUE");
+        push_conditions(
+            &mut builder,
+            "",
+            "",
+            &[],
+            0,
+            &[],
+            None,
+            None,
+            false,
+            false,
+            "",
+            Some(&after),
+            Some(&after),
+        )
+        .unwrap();
+        let sql = builder.sql();
+        assert!(sql.contains("created_at > $"));
+        assert!(sql.contains("updated_at > $"));
+    }
+
+    #[test]
+    fn filter_cursors_should_round_trip_without_the_cursor_row() {
+        let rsvp = Reservation {
+            id: "7c24e564-2a9c-4a7e-9cf5-5a1f2b3c4d5e".to_string(),
+            resource_id: "room:with:colons".to_string(),
+            status: ReservationStatus::Confirmed as i32,
+            start: Some(abi::convert_to_timestamp(
+                &chrono::DateTime::parse_from_rfc3339("2024-04-01T10:00:00Z")
+                    .unwrap()
+                    .to_utc(),
+            )),
+            ..Default::default()
+        };
+
+        // by id: the bare id, as before
+        assert_eq!(encode_cursor("id", &rsvp), rsvp.id);
+
+        // the pair survives a ':' inside the order value
+        let cursor = encode_cursor("resource_id", &rsvp);
+        let (id, value) = decode_cursor(&cursor).unwrap();
+        assert_eq!(id.to_string(), rsvp.id);
+        assert_eq!(value, "room:with:colons");
+
+        let cursor = encode_cursor("lower(timespan)", &rsvp);
+        let (_, value) = decode_cursor(&cursor).unwrap();
+        assert_eq!(
+            chrono::DateTime::parse_from_rfc3339(&value)
+                .unwrap()
+                .to_utc(),
+            chrono::DateTime::parse_from_rfc3339("2024-04-01T10:00:00Z")
+                .unwrap()
+                .to_utc()
+        );
+
+        let cursor = encode_cursor("status", &rsvp);
+        let (_, value) = decode_cursor(&cursor).unwrap();
+        assert_eq!(value, "confirmed");
+
+        // a cursor without the pair is rejected, not silently empty
+        assert!(matches!(
+            decode_cursor("not-a-pair"),
+            Err(Error::InvalidField(_))
+        ));
+    }
+
+    #[test]
+    fn full_row_notify_payloads_should_decode_without_a_fetch() {
+        // shaped exactly like the trigger's json_build_object output
+        let payload = r#"{
+            "change_id": 42,
+            "op": "update",
+            "reservation": {
+                "id": "7c24e564-2a9c-4a7e-9cf5-5a1f2b3c4d5e",
+                "user_id": "alice",
+                "resource_id": "room-101",
+                "start": "2024-04-01T10:00:00+00:00",
+                "end": "2024-04-01T12:00:00+00:00",
+                "status": "confirmed",
+                "note": "standup",
+                "version": 2,
+                "created_at": "2024-03-30T09:00:00+00:00",
+                "updated_at": "2024-04-01T09:30:00+00:00"
+            }
+        }"#;
+        let change = parse_change_payload(payload).unwrap();
+        assert_eq!(change.change_id, 42);
+        assert_eq!(change.change_type, ReservationChangeType::Update as i32);
+        let rsvp = change.reservation.unwrap();
+        assert_eq!(rsvp.user_id, "alice");
+        assert_eq!(rsvp.status, ReservationStatus::Confirmed as i32);
+        assert_eq!(rsvp.version, 2);
+        assert!(rsvp.created_at.is_some() && rsvp.updated_at.is_some());
+
+        // deletes carry no row but still decode on the fast path
+        let payload = r#"{"change_id": 43, "op": "delete", "reservation": null}"#;
+        let change = parse_change_payload(payload).unwrap();
+        assert_eq!(change.change_type, ReservationChangeType::Delete as i32);
+        assert!(change.reservation.is_none());
+
+        // the oversized-payload fallback is a bare id: no fast path
+        assert!(parse_change_payload("44").is_none());
+    }
+}
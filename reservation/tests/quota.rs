@@ -0,0 +1,97 @@
+//! Per-user quota enforcement against a real database; run with
+//! `cargo test -p reservation --features test-util` (requires Docker).
+#![cfg(feature = "test-util")]
+
+use abi::{Error, Reservation};
+use chrono::{DateTime, Utc};
+use reservation::{test_util::TestStore, ReservationManager, StoreConfig};
+
+fn at(s: &str) -> DateTime<Utc> {
+    DateTime::parse_from_rfc3339(s).unwrap().to_utc()
+}
+
+fn capped(limit: u32) -> StoreConfig {
+    StoreConfig {
+        max_active_per_user: Some(limit),
+        ..Default::default()
+    }
+}
+
+#[tokio::test]
+async fn concurrent_reserves_at_the_boundary_should_admit_exactly_one() {
+    let test = TestStore::with_config(capped(1)).await;
+
+    // one slot of headroom, two concurrent reserves racing for it: the
+    // advisory lock must let exactly one through, never both or neither
+    let first = Reservation::new_pending(
+        "alice",
+        "room-101",
+        at("2024-04-01T10:00:00Z"),
+        at("2024-04-01T12:00:00Z"),
+        "",
+    );
+    let second = Reservation::new_pending(
+        "alice",
+        "room-102",
+        at("2024-04-01T10:00:00Z"),
+        at("2024-04-01T12:00:00Z"),
+        "",
+    );
+    let (first, second) = tokio::join!(
+        test.store().reserve(first, ""),
+        test.store().reserve(second, "")
+    );
+
+    let results = [first, second];
+    assert_eq!(results.iter().filter(|r| r.is_ok()).count(), 1);
+    assert!(results
+        .iter()
+        .any(|r| matches!(r, Err(Error::QuotaExceeded { limit: 1, .. }))));
+}
+
+#[tokio::test]
+async fn batch_reserve_should_count_against_the_quota() {
+    let test = TestStore::with_config(capped(2)).await;
+    test.seed()
+        .reservation(
+            "alice",
+            "room-101",
+            "2024-04-01T10:00:00Z",
+            "2024-04-01T12:00:00Z",
+        )
+        .apply()
+        .await;
+
+    // alice has one slot left; a batch needing two must be refused whole
+    let err = test
+        .seed()
+        .reservation(
+            "alice",
+            "room-102",
+            "2024-04-01T10:00:00Z",
+            "2024-04-01T12:00:00Z",
+        )
+        .reservation(
+            "alice",
+            "room-103",
+            "2024-04-01T10:00:00Z",
+            "2024-04-01T12:00:00Z",
+        )
+        .try_apply()
+        .await
+        .unwrap_err();
+    assert!(matches!(err, Error::QuotaExceeded { limit: 2, .. }));
+
+    // a batch that fits the remaining headroom still goes through
+    let rsvps = test
+        .seed()
+        .reservation(
+            "alice",
+            "room-102",
+            "2024-04-01T10:00:00Z",
+            "2024-04-01T12:00:00Z",
+        )
+        .apply()
+        .await;
+    assert_eq!(rsvps.len(), 1);
+}
@@ -0,0 +1,98 @@
+//! Reschedule behavior against a real database; run with
+//! `cargo test -p reservation --features test-util` (requires Docker).
+#![cfg(feature = "test-util")]
+
+use abi::convert_to_utc_time;
+use chrono::{DateTime, Utc};
+use reservation::{test_util::TestStore, ReservationManager};
+
+fn at(s: &str) -> DateTime<Utc> {
+    DateTime::parse_from_rfc3339(s).unwrap().to_utc()
+}
+
+#[tokio::test]
+async fn reschedule_to_the_identical_window_should_be_a_no_op_success() {
+    let test = TestStore::new().await;
+    let rsvps = test
+        .seed()
+        .reservation(
+            "alice",
+            "room-101",
+            "2024-04-01T10:00:00Z",
+            "2024-04-01T12:00:00Z",
+        )
+        .apply()
+        .await;
+
+    let same = test
+        .store()
+        .reschedule(
+            &rsvps[0].id,
+            at("2024-04-01T10:00:00Z"),
+            at("2024-04-01T12:00:00Z"),
+            0,
+        )
+        .await
+        .unwrap();
+    // nothing moved, so nothing was written: the version must not bump
+    assert_eq!(same.version, rsvps[0].version);
+    assert_eq!(same.start, rsvps[0].start);
+    assert_eq!(same.end, rsvps[0].end);
+}
+
+#[tokio::test]
+async fn reschedule_should_move_only_the_changed_bound() {
+    let test = TestStore::new().await;
+    let rsvps = test
+        .seed()
+        .reservation(
+            "alice",
+            "room-101",
+            "2024-04-01T10:00:00Z",
+            "2024-04-01T12:00:00Z",
+        )
+        .apply()
+        .await;
+
+    // only the start changes
+    let moved = test
+        .store()
+        .reschedule(
+            &rsvps[0].id,
+            at("2024-04-01T09:00:00Z"),
+            at("2024-04-01T12:00:00Z"),
+            0,
+        )
+        .await
+        .unwrap();
+    assert_eq!(
+        convert_to_utc_time(moved.start.as_ref().unwrap()),
+        at("2024-04-01T09:00:00Z")
+    );
+    assert_eq!(
+        convert_to_utc_time(moved.end.as_ref().unwrap()),
+        at("2024-04-01T12:00:00Z")
+    );
+    assert_eq!(moved.version, rsvps[0].version + 1);
+
+    // only the end changes
+    let moved = test
+        .store()
+        .reschedule(
+            &rsvps[0].id,
+            at("2024-04-01T09:00:00Z"),
+            at("2024-04-01T11:00:00Z"),
+            0,
+        )
+        .await
+        .unwrap();
+    assert_eq!(
+        convert_to_utc_time(moved.start.as_ref().unwrap()),
+        at("2024-04-01T09:00:00Z")
+    );
+    assert_eq!(
+        convert_to_utc_time(moved.end.as_ref().unwrap()),
+        at("2024-04-01T11:00:00Z")
+    );
+    assert_eq!(moved.version, rsvps[0].version + 2);
+}
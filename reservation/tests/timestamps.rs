@@ -0,0 +1,34 @@
+//! Audit-timestamp behavior against a real database; run with
+//! `cargo test -p reservation --features test-util` (requires Docker).
+#![cfg(feature = "test-util")]
+
+use abi::convert_to_utc_time;
+use reservation::{test_util::TestStore, ReservationManager};
+
+#[tokio::test]
+async fn updated_at_should_advance_while_created_at_stays_fixed() {
+    let test = TestStore::new().await;
+    let rsvps = test
+        .seed()
+        .reservation(
+            "alice",
+            "room-101",
+            "2024-04-01T10:00:00Z",
+            "2024-04-01T12:00:00Z",
+        )
+        .apply()
+        .await;
+    let created = convert_to_utc_time(rsvps[0].created_at.as_ref().unwrap());
+    let updated = convert_to_utc_time(rsvps[0].updated_at.as_ref().unwrap());
+
+    let confirmed = test.store().confirm(&rsvps[0].id).await.unwrap();
+    assert_eq!(
+        convert_to_utc_time(confirmed.created_at.as_ref().unwrap()),
+        created,
+        "created_at must never change after insert"
+    );
+    assert!(
+        convert_to_utc_time(confirmed.updated_at.as_ref().unwrap()) > updated,
+        "the update trigger must advance updated_at on confirm"
+    );
+}
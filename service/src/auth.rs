@@ -0,0 +1,224 @@
+use std::{collections::HashMap, sync::Arc};
+
+use tonic::{service::Interceptor, Request, Status};
+
+/// The authenticated caller, resolved from the request's bearer token and
+/// stored in the request extensions by [`AuthInterceptor`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Principal {
+    /// The user this caller acts as; ownership-checked RPCs compare it
+    /// against the reservation's `user_id`.
+    pub user_id: String,
+    /// Admins bypass ownership checks.
+    pub admin: bool,
+}
+
+/// Resolve a bearer token into a principal. Implementations can look the
+/// token up in a fixed table ([`StaticTokens`]), verify a JWT, or call out
+/// to an external service; reject missing or unknown tokens with
+/// `Status::unauthenticated`.
+pub trait Authenticator: Send + Sync + 'static {
+    #[allow(clippy::result_large_err)]
+    fn authenticate(&self, token: Option<&str>) -> Result<Principal, Status>;
+}
+
+/// Development resolver: every caller, token or not, is an admin.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct AllowAll;
+
+impl Authenticator for AllowAll {
+    fn authenticate(&self, _token: Option<&str>) -> Result<Principal, Status> {
+        Ok(Principal {
+            user_id: String::new(),
+            admin: true,
+        })
+    }
+}
+
+/// Fixed token table, enough for small deployments and tests.
+#[derive(Debug, Clone, Default)]
+pub struct StaticTokens {
+    tokens: HashMap<String, Principal>,
+}
+
+impl StaticTokens {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register `token` as acting for `user_id`, optionally with the admin
+    /// scope.
+    pub fn with_token(
+        mut self,
+        token: impl Into<String>,
+        user_id: impl Into<String>,
+        admin: bool,
+    ) -> Self {
+        self.tokens.insert(
+            token.into(),
+            Principal {
+                user_id: user_id.into(),
+                admin,
+            },
+        );
+        self
+    }
+}
+
+impl Authenticator for StaticTokens {
+    fn authenticate(&self, token: Option<&str>) -> Result<Principal, Status> {
+        let token = token.ok_or_else(|| Status::unauthenticated("missing bearer token"))?;
+        self.tokens
+            .get(token)
+            .cloned()
+            .ok_or_else(|| Status::unauthenticated("unknown token"))
+    }
+}
+
+/// Interceptor that authenticates every request up front: it reads the
+/// `authorization` metadata (with or without the `Bearer ` prefix), resolves
+/// it through the configured [`Authenticator`], and stores the resulting
+/// [`Principal`] in the request extensions for [`ensure_owner`] to check.
+#[derive(Clone)]
+pub struct AuthInterceptor {
+    resolver: Arc<dyn Authenticator>,
+}
+
+impl AuthInterceptor {
+    pub fn new(resolver: Arc<dyn Authenticator>) -> Self {
+        Self { resolver }
+    }
+}
+
+impl Interceptor for AuthInterceptor {
+    fn call(&mut self, mut request: Request<()>) -> Result<Request<()>, Status> {
+        let token = request
+            .metadata()
+            .get("authorization")
+            .and_then(|value| value.to_str().ok())
+            .map(|value| value.strip_prefix("Bearer ").unwrap_or(value).to_string());
+        let principal = self.resolver.authenticate(token.as_deref())?;
+        request.extensions_mut().insert(principal);
+        Ok(request)
+    }
+}
+
+/// Check that the caller may act on a reservation owned by `user_id`:
+/// admins always may, everyone else only on their own. Requests that never
+/// went through [`AuthInterceptor`] carry no principal and pass unchecked,
+/// so embedding [`RsvpService`](crate::RsvpService) without the interceptor
+/// keeps working.
+#[allow(clippy::result_large_err)]
+pub fn ensure_owner<T>(request: &Request<T>, user_id: &str) -> Result<(), Status> {
+    match request.extensions().get::<Principal>() {
+        None => Ok(()),
+        Some(principal) if principal.admin || principal.user_id == user_id => Ok(()),
+        Some(_) => Err(Status::permission_denied(format!(
+            "not allowed to act on reservations of {user_id}"
+        ))),
+    }
+}
+
+/// Check that the caller holds the admin scope, for destructive operations
+/// like `delete_by_query`. Follows the same stance as [`ensure_owner`]:
+/// requests without a principal pass unchecked.
+#[allow(clippy::result_large_err)]
+pub fn ensure_admin<T>(request: &Request<T>) -> Result<(), Status> {
+    match request.extensions().get::<Principal>() {
+        None => Ok(()),
+        Some(principal) if principal.admin => Ok(()),
+        Some(_) => Err(Status::permission_denied(
+            "this operation requires the admin scope",
+        )),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tonic::Code;
+
+    fn request_with_token(token: &str) -> Request<()> {
+        let mut request = Request::new(());
+        request
+            .metadata_mut()
+            .insert("authorization", token.parse().unwrap());
+        request
+    }
+
+    #[test]
+    fn static_tokens_should_resolve_to_the_registered_principal() {
+        let mut interceptor = AuthInterceptor::new(Arc::new(
+            StaticTokens::new().with_token("secret", "alice", false),
+        ));
+        let request = interceptor
+            .call(request_with_token("Bearer secret"))
+            .unwrap();
+        let principal = request.extensions().get::<Principal>().unwrap();
+        assert_eq!(principal.user_id, "alice");
+        assert!(!principal.admin);
+    }
+
+    #[test]
+    fn unknown_or_missing_tokens_should_be_unauthenticated() {
+        let mut interceptor = AuthInterceptor::new(Arc::new(
+            StaticTokens::new().with_token("secret", "alice", false),
+        ));
+        let status = interceptor
+            .call(request_with_token("Bearer wrong"))
+            .unwrap_err();
+        assert_eq!(status.code(), Code::Unauthenticated);
+        let status = interceptor.call(Request::new(())).unwrap_err();
+        assert_eq!(status.code(), Code::Unauthenticated);
+    }
+
+    #[test]
+    fn allow_all_should_grant_admin_without_a_token() {
+        let mut interceptor = AuthInterceptor::new(Arc::new(AllowAll));
+        let request = interceptor.call(Request::new(())).unwrap();
+        assert!(request.extensions().get::<Principal>().unwrap().admin);
+    }
+
+    #[test]
+    fn ensure_owner_should_enforce_ownership() {
+        // no interceptor installed: unchecked
+        assert!(ensure_owner(&Request::new(()), "alice").is_ok());
+
+        let mut request = Request::new(());
+        request.extensions_mut().insert(Principal {
+            user_id: "alice".to_string(),
+            admin: false,
+        });
+        assert!(ensure_owner(&request, "alice").is_ok());
+        let status = ensure_owner(&request, "bob").unwrap_err();
+        assert_eq!(status.code(), Code::PermissionDenied);
+
+        let mut request = Request::new(());
+        request.extensions_mut().insert(Principal {
+            user_id: String::new(),
+            admin: true,
+        });
+        assert!(ensure_owner(&request, "bob").is_ok());
+    }
+
+    #[test]
+    fn ensure_admin_should_require_the_admin_scope() {
+        // no interceptor installed: unchecked
+        assert!(ensure_admin(&Request::new(())).is_ok());
+
+        let mut request = Request::new(());
+        request.extensions_mut().insert(Principal {
+            user_id: "alice".to_string(),
+            admin: false,
+        });
+        let status = ensure_admin(&request).unwrap_err();
+        assert_eq!(status.code(), Code::PermissionDenied);
+
+        let mut request = Request::new(());
+        request.extensions_mut().insert(Principal {
+            user_id: String::new(),
+            admin: true,
+        });
+        assert!(ensure_admin(&request).is_ok());
+    }
+}
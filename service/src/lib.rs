@@ -0,0 +1,11 @@
+mod auth;
+mod server;
+mod service;
+mod tls;
+
+pub use auth::{
+    ensure_admin, ensure_owner, AllowAll, AuthInterceptor, Authenticator, Principal, StaticTokens,
+};
+pub use server::serve_with_shutdown;
+pub use service::RsvpService;
+pub use tls::{client_identity, ClientIdentity, TlsSettings};
@@ -1,3 +1,94 @@
-fn main() {
-    println!("Hello, world!");
+use std::time::Duration;
+
+use std::sync::Arc;
+
+use anyhow::{Context, Result};
+use reservation::PgStore;
+use reservation_service::{serve_with_shutdown, AllowAll, TlsSettings};
+use tokio::signal::unix::{signal, SignalKind};
+
+/// How long in-flight RPCs and streams get to drain after SIGTERM before the
+/// process exits anyway. Override with RESERVATION_SHUTDOWN_GRACE (seconds).
+const DEFAULT_SHUTDOWN_GRACE: Duration = Duration::from_secs(30);
+
+#[tokio::main]
+async fn main() -> Result<()> {
+    // RUST_LOG controls verbosity; spans carry request_id and reservation ids
+    tracing_subscriber::fmt()
+        .with_env_filter(tracing_subscriber::EnvFilter::from_default_env())
+        .init();
+
+    let url = std::env::var("DATABASE_URL")?;
+    let addr = std::env::var("RESERVATION_ADDR")
+        .unwrap_or_else(|_| "0.0.0.0:50051".to_string())
+        .parse()?;
+    let grace = std::env::var("RESERVATION_SHUTDOWN_GRACE")
+        .ok()
+        .and_then(|s| s.parse().ok())
+        .map(Duration::from_secs)
+        .unwrap_or(DEFAULT_SHUTDOWN_GRACE);
+
+    let tls = load_tls()?;
+
+    // Prometheus scrapes GET /metrics here; gRPC can't serve plain HTTP, so
+    // the exporter runs its own small listener
+    let metrics_addr: std::net::SocketAddr = std::env::var("RESERVATION_METRICS_ADDR")
+        .unwrap_or_else(|_| "0.0.0.0:9464".to_string())
+        .parse()?;
+    metrics_exporter_prometheus::PrometheusBuilder::new()
+        .with_http_listener(metrics_addr)
+        .install()
+        .context("failed to start the metrics endpoint")?;
+    reservation::metrics::describe();
+
+    let store = PgStore::from_url(&url).await?;
+
+    tracing::info!("reservation service listening on {addr}");
+    // swap AllowAll for a real Authenticator to enforce ownership checks
+    serve_with_shutdown(
+        store,
+        addr,
+        tls,
+        Arc::new(AllowAll),
+        shutdown_signal(),
+        grace,
+    )
+    .await?;
+    tracing::info!("reservation service shut down");
+    Ok(())
+}
+
+/// Build the TLS config from RESERVATION_TLS_CERT / RESERVATION_TLS_KEY,
+/// with RESERVATION_TLS_CLIENT_CA turning on mutual TLS. No cert and no key
+/// means plaintext.
+fn load_tls() -> Result<Option<tonic::transport::ServerTlsConfig>> {
+    let cert = std::env::var("RESERVATION_TLS_CERT").ok();
+    let key = std::env::var("RESERVATION_TLS_KEY").ok();
+    let settings = match (cert, key) {
+        (Some(cert), Some(key)) => TlsSettings {
+            cert: cert.into(),
+            key: key.into(),
+            client_ca: std::env::var("RESERVATION_TLS_CLIENT_CA")
+                .ok()
+                .map(Into::into),
+        },
+        (None, None) => return Ok(None),
+        _ => anyhow::bail!("RESERVATION_TLS_CERT and RESERVATION_TLS_KEY must be set together"),
+    };
+    let config = settings.load().context("invalid TLS configuration")?;
+    tracing::info!(
+        mtls = settings.client_ca.is_some(),
+        "TLS enabled with certificate {}",
+        settings.cert.display()
+    );
+    Ok(Some(config))
+}
+
+/// Resolves on SIGTERM (what orchestrators send) or ctrl-c (local runs).
+async fn shutdown_signal() {
+    let mut sigterm = signal(SignalKind::terminate()).expect("failed to install SIGTERM handler");
+    tokio::select! {
+        _ = sigterm.recv() => {}
+        _ = tokio::signal::ctrl_c() => {}
+    }
 }
@@ -0,0 +1,123 @@
+use std::{future::Future, net::SocketAddr, sync::Arc, time::Duration};
+
+use abi::reservation_service_server::ReservationServiceServer;
+use reservation::PgStore;
+use sqlx::PgPool;
+use tokio::sync::oneshot;
+use tonic::transport::{Server, ServerTlsConfig};
+use tonic_health::server::HealthReporter;
+
+use crate::{auth::AuthInterceptor, Authenticator, RsvpService};
+
+/// How often the readiness task re-checks the database connection.
+const HEALTH_CHECK_INTERVAL: Duration = Duration::from_secs(10);
+
+/// Serve until `signal` resolves, then shut down gracefully: stop accepting
+/// new RPCs, give in-flight handlers and streams up to `grace` to finish
+/// their current batch, and close the pool once they have drained (or the
+/// grace period runs out). Closing the pool also kicks any LISTEN/NOTIFY
+/// watcher still subscribed, ending its stream cleanly.
+///
+/// Pass `tls` to terminate TLS in-process; build it with
+/// [`TlsSettings::load`](crate::TlsSettings::load). When the config carries
+/// a client CA the server requires mutual TLS, and handlers can read the
+/// verified identity with [`client_identity`](crate::client_identity).
+///
+/// Every reservation RPC goes through `auth` (see [`Authenticator`]); use
+/// [`AllowAll`](crate::AllowAll) to serve without authorization.
+pub async fn serve_with_shutdown<F>(
+    store: PgStore,
+    addr: SocketAddr,
+    tls: Option<ServerTlsConfig>,
+    auth: Arc<dyn Authenticator>,
+    signal: F,
+    grace: Duration,
+) -> Result<(), tonic::transport::Error>
+where
+    F: Future<Output = ()> + Send + 'static,
+{
+    // configure transport first so a bad TLS config fails before any
+    // background task starts
+    let mut builder = Server::builder();
+    if let Some(tls) = tls {
+        builder = builder.tls_config(tls)?;
+    }
+
+    let pool = store.pool().clone();
+    // releases expired holds in the background for as long as we serve
+    let sweeper = store.start_sweeper();
+    // keeps the active-reservations gauge fresh; a no-op without a recorder
+    let metrics_task = store.start_metrics();
+    let service = RsvpService::new(store);
+
+    // standard gRPC health service (grpc.health.v1.Health); probe it with the
+    // service name "reservation.ReservationService", or "" for the whole server
+    let (health_reporter, health_service) = tonic_health::server::health_reporter();
+    health_reporter
+        .clone()
+        .set_serving::<ReservationServiceServer<RsvpService>>()
+        .await;
+    let health_task = tokio::spawn(watch_db_health(pool.clone(), health_reporter));
+
+    // relay the signal so we know when draining started; if the server stops
+    // on its own the sender is dropped and the await below resolves too
+    let (shutdown_tx, shutdown_rx) = oneshot::channel();
+    let signal = async move {
+        signal.await;
+        let _ = shutdown_tx.send(());
+    };
+    let mut server = tokio::spawn(
+        builder
+            // every handler and store span nests under this one, so a
+            // client-supplied x-request-id correlates the whole booking;
+            // with no subscriber installed the span is a no-op
+            .trace_fn(|req| {
+                let request_id = req
+                    .headers()
+                    .get("x-request-id")
+                    .and_then(|v| v.to_str().ok())
+                    .unwrap_or_default();
+                tracing::info_span!("request", %request_id, path = %req.uri().path())
+            })
+            .add_service(health_service)
+            .add_service(ReservationServiceServer::with_interceptor(
+                service,
+                AuthInterceptor::new(auth),
+            ))
+            .serve_with_shutdown(addr, signal),
+    );
+
+    let _ = shutdown_rx.await;
+    let result = match tokio::time::timeout(grace, &mut server).await {
+        Ok(joined) => joined.expect("server task panicked"),
+        Err(_) => {
+            // grace expired: abandon whatever streams are still running
+            server.abort();
+            Ok(())
+        }
+    };
+    health_task.abort();
+    sweeper.abort();
+    metrics_task.abort();
+    pool.close().await;
+    result
+}
+
+/// Flip the reservation service between SERVING and NOT_SERVING based on
+/// whether the pool can actually reach Postgres, so a transient outage
+/// self-heals without a restart.
+async fn watch_db_health(pool: PgPool, mut reporter: HealthReporter) {
+    let mut interval = tokio::time::interval(HEALTH_CHECK_INTERVAL);
+    loop {
+        interval.tick().await;
+        if sqlx::query("SELECT 1").execute(&pool).await.is_ok() {
+            reporter
+                .set_serving::<ReservationServiceServer<RsvpService>>()
+                .await;
+        } else {
+            reporter
+                .set_not_serving::<ReservationServiceServer<RsvpService>>()
+                .await;
+        }
+    }
+}
@@ -0,0 +1,462 @@
+use abi::{
+    convert_to_utc_time, expand_recurrence, reservation_service_server::ReservationService,
+    AggregateRequest, AggregateResponse, ArchiveRequest, ArchiveResponse, BatchReserveRequest,
+    BatchReserveResponse, BlockRequest, BlockResponse, BulkImportRequest, BulkImportResponse,
+    CalendarRequest, CalendarResponse, CancelRequest, CancelResponse, CheckAvailabilityRequest,
+    CheckAvailabilityResponse, ConfirmRequest, ConfirmResponse, CountRequest, CountResponse,
+    DeleteByQueryRequest, DeleteByQueryResponse, Error, FilterRequest, FilterResponse, GetRequest,
+    GetResponse, MergeRequest, MergeResponse, PollChangesRequest, PollChangesResponse,
+    QueryRequest, RescheduleRequest, RescheduleResponse, Reservation, ReservationFilter,
+    ReserveRecurringRequest, ReserveRecurringResponse, ReserveRequest, ReserveResponse,
+    ResourceCount, SplitRequest, SplitResponse, UpdateRequest, UpdateResponse, Validate,
+    WatchRequest, WatchResponse,
+};
+use std::pin::Pin;
+
+use crate::{ensure_admin, ensure_owner};
+use reservation::{PgStore, ReservationManager};
+use tokio::sync::mpsc;
+use tokio_stream::{wrappers::ReceiverStream, Stream, StreamExt};
+use tonic::{Request, Response, Status, Streaming};
+
+/// gRPC front for the reservation manager.
+pub struct RsvpService {
+    manager: PgStore,
+}
+
+impl RsvpService {
+    pub fn new(store: PgStore) -> Self {
+        Self { manager: store }
+    }
+
+    pub async fn from_url(url: &str) -> Result<Self, Error> {
+        Ok(Self::new(PgStore::from_url(url).await?))
+    }
+}
+
+#[tonic::async_trait]
+impl ReservationService for RsvpService {
+    async fn reserve(
+        &self,
+        request: Request<ReserveRequest>,
+    ) -> Result<Response<ReserveResponse>, Status> {
+        if let Some(rsvp) = &request.get_ref().reservation {
+            ensure_owner(&request, &rsvp.user_id)?;
+        }
+        let request = request.into_inner();
+        request.validate().map_err(Status::from)?;
+        let rsvp = self
+            .manager
+            .reserve(request.reservation.unwrap(), &request.idempotency_key)
+            .await?;
+        Ok(Response::new(ReserveResponse {
+            reservation: Some(rsvp),
+        }))
+    }
+
+    async fn block(
+        &self,
+        request: Request<BlockRequest>,
+    ) -> Result<Response<BlockResponse>, Status> {
+        // blocks are maintenance windows under the system sentinel, not
+        // user-owned rows: only admins may take a resource out of booking
+        ensure_admin(&request)?;
+        let request = request.into_inner();
+        let start = request
+            .start
+            .as_ref()
+            .map(convert_to_utc_time)
+            .ok_or(Error::InvalidTime)?;
+        let end = request
+            .end
+            .as_ref()
+            .map(convert_to_utc_time)
+            .ok_or(Error::InvalidTime)?;
+        let rsvp = self
+            .manager
+            .block(&request.resource_id, start, end, &request.note)
+            .await?;
+        Ok(Response::new(BlockResponse {
+            reservation: Some(rsvp),
+        }))
+    }
+
+    async fn batch_reserve(
+        &self,
+        request: Request<BatchReserveRequest>,
+    ) -> Result<Response<BatchReserveResponse>, Status> {
+        // every reservation in the batch must belong to the caller
+        for info in &request.get_ref().reservations {
+            ensure_owner(&request, &info.user_id)?;
+        }
+        let request = request.into_inner();
+        let rsvps = self.manager.batch_reserve(request.reservations).await?;
+        Ok(Response::new(BatchReserveResponse {
+            reservations: rsvps,
+        }))
+    }
+
+    async fn reserve_recurring(
+        &self,
+        request: Request<ReserveRecurringRequest>,
+    ) -> Result<Response<ReserveRecurringResponse>, Status> {
+        // every occurrence inherits the base reservation's user, so checking
+        // the base covers the whole series
+        if let Some(base) = &request.get_ref().reservation {
+            ensure_owner(&request, &base.user_id)?;
+        }
+        let request = request.into_inner();
+        let base = request
+            .reservation
+            .ok_or_else(|| Status::invalid_argument("missing reservation"))?;
+        let rule = request
+            .rule
+            .ok_or_else(|| Status::invalid_argument("missing rule"))?;
+        let occurrences = expand_recurrence(&base, &rule).map_err(Status::from)?;
+        // the whole series goes through the batch transaction, so the series
+        // is conflict-checked together
+        let rsvps = self.manager.batch_reserve(occurrences).await?;
+        Ok(Response::new(ReserveRecurringResponse {
+            reservations: rsvps,
+        }))
+    }
+
+    async fn confirm(
+        &self,
+        request: Request<ConfirmRequest>,
+    ) -> Result<Response<ConfirmResponse>, Status> {
+        let current = self.manager.get(&request.get_ref().id).await?;
+        ensure_owner(&request, &current.user_id)?;
+        let request = request.into_inner();
+        let rsvp = self.manager.confirm(&request.id).await?;
+        Ok(Response::new(ConfirmResponse {
+            reservation: Some(rsvp),
+        }))
+    }
+
+    async fn update(
+        &self,
+        request: Request<UpdateRequest>,
+    ) -> Result<Response<UpdateResponse>, Status> {
+        // ownership lives on the stored row, so look it up before mutating
+        let current = self.manager.get(&request.get_ref().id).await?;
+        ensure_owner(&request, &current.user_id)?;
+        let request = request.into_inner();
+        request.validate().map_err(Status::from)?;
+        let rsvp = self.manager.update(request).await?;
+        Ok(Response::new(UpdateResponse {
+            reservation: Some(rsvp),
+        }))
+    }
+
+    async fn cancel(
+        &self,
+        request: Request<CancelRequest>,
+    ) -> Result<Response<CancelResponse>, Status> {
+        let current = self.manager.get(&request.get_ref().id).await?;
+        ensure_owner(&request, &current.user_id)?;
+        let request = request.into_inner();
+        let rsvp = self.manager.cancel(&request.id).await?;
+        Ok(Response::new(CancelResponse {
+            reservation: Some(rsvp),
+        }))
+    }
+
+    async fn archive(
+        &self,
+        request: Request<ArchiveRequest>,
+    ) -> Result<Response<ArchiveResponse>, Status> {
+        let current = self.manager.get(&request.get_ref().id).await?;
+        ensure_owner(&request, &current.user_id)?;
+        let request = request.into_inner();
+        let rsvp = self.manager.archive(&request.id).await?;
+        Ok(Response::new(ArchiveResponse {
+            reservation: Some(rsvp),
+        }))
+    }
+
+    async fn reschedule(
+        &self,
+        request: Request<RescheduleRequest>,
+    ) -> Result<Response<RescheduleResponse>, Status> {
+        let current = self.manager.get(&request.get_ref().id).await?;
+        ensure_owner(&request, &current.user_id)?;
+        let request = request.into_inner();
+        let start = request
+            .start
+            .as_ref()
+            .map(convert_to_utc_time)
+            .ok_or(Error::InvalidTime)?;
+        let end = request
+            .end
+            .as_ref()
+            .map(convert_to_utc_time)
+            .ok_or(Error::InvalidTime)?;
+        let rsvp = self
+            .manager
+            .reschedule(&request.id, start, end, request.expected_version)
+            .await?;
+        Ok(Response::new(RescheduleResponse {
+            reservation: Some(rsvp),
+        }))
+    }
+
+    async fn split(
+        &self,
+        request: Request<SplitRequest>,
+    ) -> Result<Response<SplitResponse>, Status> {
+        let current = self.manager.get(&request.get_ref().id).await?;
+        ensure_owner(&request, &current.user_id)?;
+        let request = request.into_inner();
+        let at = request
+            .at
+            .as_ref()
+            .map(convert_to_utc_time)
+            .ok_or(Error::InvalidTime)?;
+        let (first, second) = self.manager.split(&request.id, at).await?;
+        Ok(Response::new(SplitResponse {
+            first: Some(first),
+            second: Some(second),
+        }))
+    }
+
+    async fn merge(
+        &self,
+        request: Request<MergeRequest>,
+    ) -> Result<Response<MergeResponse>, Status> {
+        // both halves share a user (the store enforces it), so checking one
+        // covers the pair
+        let current = self.manager.get(&request.get_ref().id_a).await?;
+        ensure_owner(&request, &current.user_id)?;
+        let request = request.into_inner();
+        let rsvp = self.manager.merge(&request.id_a, &request.id_b).await?;
+        Ok(Response::new(MergeResponse {
+            reservation: Some(rsvp),
+        }))
+    }
+
+    async fn get(&self, request: Request<GetRequest>) -> Result<Response<GetResponse>, Status> {
+        let request = request.into_inner();
+        let rsvp = self.manager.get(&request.id).await?;
+        Ok(Response::new(GetResponse {
+            reservation: Some(rsvp),
+        }))
+    }
+
+    type queryStream = ReceiverStream<Result<Reservation, Status>>;
+
+    async fn query(
+        &self,
+        request: Request<QueryRequest>,
+    ) -> Result<Response<Self::queryStream>, Status> {
+        let request = request.into_inner();
+        let query = request
+            .query
+            .ok_or_else(|| Status::invalid_argument("missing query"))?;
+
+        // fetch pages lazily and forward rows, so large result sets never
+        // fully materialize on the server
+        let manager = self.manager.clone();
+        let (tx, rx) = mpsc::channel(128);
+        tokio::spawn(async move {
+            let mut filter = ReservationFilter::from(query);
+            loop {
+                match manager.filter(filter.clone()).await {
+                    Ok(page) => {
+                        for rsvp in page.reservations {
+                            if tx.send(Ok(rsvp)).await.is_err() {
+                                return;
+                            }
+                        }
+                        if page.next_cursor.is_empty() {
+                            return;
+                        }
+                        filter.cursor = page.next_cursor;
+                    }
+                    Err(e) => {
+                        let _ = tx.send(Err(e.into())).await;
+                        return;
+                    }
+                }
+            }
+        });
+        Ok(Response::new(ReceiverStream::new(rx)))
+    }
+
+    async fn filter(
+        &self,
+        request: Request<FilterRequest>,
+    ) -> Result<Response<FilterResponse>, Status> {
+        let request = request.into_inner();
+        let filter = request
+            .filter
+            .ok_or_else(|| Status::invalid_argument("missing filter"))?;
+        let response = self.manager.filter(filter).await?;
+        Ok(Response::new(response))
+    }
+
+    async fn aggregate_by_resource(
+        &self,
+        request: Request<AggregateRequest>,
+    ) -> Result<Response<AggregateResponse>, Status> {
+        let request = request.into_inner();
+        let resources = self
+            .manager
+            .aggregate_by_resource(request.query.unwrap_or_default(), request.top_n)
+            .await?;
+        Ok(Response::new(AggregateResponse {
+            resources: resources
+                .into_iter()
+                .map(|(resource_id, count)| ResourceCount { resource_id, count })
+                .collect(),
+        }))
+    }
+
+    async fn bulk_import(
+        &self,
+        request: Request<Streaming<BulkImportRequest>>,
+    ) -> Result<Response<BulkImportResponse>, Status> {
+        // a seeding/migration operation in the same class as delete_by_query:
+        // it loads rows for arbitrary users over COPY, outside the per-user
+        // quota and (with allow_overlaps) the capacity checks
+        ensure_admin(&request)?;
+        let mut stream = request.into_inner();
+        // allow_overlaps comes from the first message; later values are
+        // ignored so the whole batch loads under one mode
+        let first = stream
+            .message()
+            .await?
+            .ok_or_else(|| Status::invalid_argument("empty import stream"))?;
+        let allow_overlaps = first.allow_overlaps;
+
+        let (tx, rx) = mpsc::channel(1024);
+        tokio::spawn(async move {
+            if let Some(info) = first.reservation {
+                if tx.send(info).await.is_err() {
+                    return;
+                }
+            }
+            while let Ok(Some(request)) = stream.message().await {
+                let Some(info) = request.reservation else {
+                    continue;
+                };
+                if tx.send(info).await.is_err() {
+                    return;
+                }
+            }
+        });
+        let summary = self.manager.bulk_import(rx, allow_overlaps).await?;
+        Ok(Response::new(summary))
+    }
+
+    async fn delete_by_query(
+        &self,
+        request: Request<DeleteByQueryRequest>,
+    ) -> Result<Response<DeleteByQueryResponse>, Status> {
+        // destructive: only admins may bulk-delete
+        ensure_admin(&request)?;
+        let request = request.into_inner();
+        let deleted = self
+            .manager
+            .delete_by_query(
+                request.query.unwrap_or_default(),
+                request.confirm_delete_all,
+            )
+            .await?;
+        Ok(Response::new(DeleteByQueryResponse { deleted }))
+    }
+
+    async fn calendar(
+        &self,
+        request: Request<CalendarRequest>,
+    ) -> Result<Response<CalendarResponse>, Status> {
+        let request = request.into_inner();
+        let start = request
+            .start
+            .as_ref()
+            .map(convert_to_utc_time)
+            .ok_or(Error::InvalidTime)?;
+        let end = request
+            .end
+            .as_ref()
+            .map(convert_to_utc_time)
+            .ok_or(Error::InvalidTime)?;
+        let slots = self
+            .manager
+            .calendar(
+                &request.resource_id,
+                start,
+                end,
+                chrono::Duration::seconds(request.slot_seconds),
+            )
+            .await?;
+        Ok(Response::new(CalendarResponse { slots }))
+    }
+
+    async fn check_availability(
+        &self,
+        request: Request<CheckAvailabilityRequest>,
+    ) -> Result<Response<CheckAvailabilityResponse>, Status> {
+        let request = request.into_inner();
+        let start = request
+            .start
+            .as_ref()
+            .map(convert_to_utc_time)
+            .ok_or(Error::InvalidTime)?;
+        let end = request
+            .end
+            .as_ref()
+            .map(convert_to_utc_time)
+            .ok_or(Error::InvalidTime)?;
+        let conflicting_ids = self
+            .manager
+            .check_availability(&request.resource_id, start, end)
+            .await?;
+        Ok(Response::new(CheckAvailabilityResponse {
+            available: conflicting_ids.is_empty(),
+            conflicting_ids,
+        }))
+    }
+
+    async fn count(
+        &self,
+        request: Request<CountRequest>,
+    ) -> Result<Response<CountResponse>, Status> {
+        let request = request.into_inner();
+        // no query means "count everything"
+        let count = self
+            .manager
+            .count(request.query.unwrap_or_default())
+            .await?;
+        Ok(Response::new(CountResponse { count }))
+    }
+
+    type watchStream = Pin<Box<dyn Stream<Item = Result<WatchResponse, Status>> + Send>>;
+
+    async fn watch(
+        &self,
+        request: Request<WatchRequest>,
+    ) -> Result<Response<Self::watchStream>, Status> {
+        let request = request.into_inner();
+        let rx = self.manager.watch(request.resume_from).await?;
+        // tonic dictates the size of `Status` here
+        #[allow(clippy::result_large_err)]
+        let stream = ReceiverStream::new(rx).map(|item| item.map_err(Status::from));
+        Ok(Response::new(Box::pin(stream)))
+    }
+
+    async fn poll_changes(
+        &self,
+        request: Request<PollChangesRequest>,
+    ) -> Result<Response<PollChangesResponse>, Status> {
+        let request = request.into_inner();
+        let response = self
+            .manager
+            .poll_changes(
+                request.resume_from,
+                chrono::Duration::seconds(request.wait_seconds),
+            )
+            .await?;
+        Ok(Response::new(response))
+    }
+}
@@ -0,0 +1,132 @@
+use std::path::{Path, PathBuf};
+
+use tonic::{
+    transport::{Certificate, Identity, ServerTlsConfig},
+    Request,
+};
+use x509_parser::prelude::{FromDer, GeneralName, X509Certificate};
+
+/// File-based TLS settings for the server. `load` turns them into the
+/// [`ServerTlsConfig`] that [`serve_with_shutdown`] accepts, reporting
+/// missing or non-PEM files up front instead of failing once the first
+/// connection arrives.
+///
+/// [`serve_with_shutdown`]: crate::serve_with_shutdown
+#[derive(Debug, Clone)]
+pub struct TlsSettings {
+    /// PEM encoded server certificate (leaf first, then any intermediates).
+    pub cert: PathBuf,
+    /// PEM encoded private key for the certificate.
+    pub key: PathBuf,
+    /// PEM encoded CA bundle for verifying client certificates. Setting it
+    /// turns on mutual TLS: connections without a certificate signed by
+    /// this CA are rejected during the handshake.
+    pub client_ca: Option<PathBuf>,
+}
+
+impl TlsSettings {
+    /// Read the referenced files and build the tonic TLS config.
+    pub fn load(&self) -> Result<ServerTlsConfig, std::io::Error> {
+        let cert = read_pem(&self.cert)?;
+        let key = read_pem(&self.key)?;
+        let mut config = ServerTlsConfig::new().identity(Identity::from_pem(cert, key));
+        if let Some(ca) = &self.client_ca {
+            config = config.client_ca_root(Certificate::from_pem(read_pem(ca)?));
+        }
+        Ok(config)
+    }
+}
+
+/// Read a file that must contain PEM data, wrapping errors with the path so
+/// a misconfigured deployment says which file is the problem.
+fn read_pem(path: &Path) -> Result<Vec<u8>, std::io::Error> {
+    let data = std::fs::read(path).map_err(|e| {
+        std::io::Error::new(e.kind(), format!("failed to read {}: {e}", path.display()))
+    })?;
+    const MARKER: &[u8] = b"-----BEGIN ";
+    if !data.windows(MARKER.len()).any(|window| window == MARKER) {
+        return Err(std::io::Error::new(
+            std::io::ErrorKind::InvalidData,
+            format!("no PEM data in {}", path.display()),
+        ));
+    }
+    Ok(data)
+}
+
+/// Identity fields taken from the verified client certificate on a mutual
+/// TLS connection.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ClientIdentity {
+    /// Subject common name, if the certificate carries one.
+    pub common_name: Option<String>,
+    /// DNS entries from the subject alternative name extension.
+    pub dns_names: Vec<String>,
+}
+
+/// Extract the caller's identity from the leaf certificate rustls verified
+/// during the handshake. Returns `None` on plaintext or server-only TLS
+/// connections, so handlers can authorize on it only when mTLS is on.
+pub fn client_identity<T>(request: &Request<T>) -> Option<ClientIdentity> {
+    let certs = request.peer_certs()?;
+    let leaf = certs.first()?;
+    let (_, cert) = X509Certificate::from_der(leaf.as_ref()).ok()?;
+
+    let common_name = cert
+        .subject()
+        .iter_common_name()
+        .next()
+        .and_then(|cn| cn.as_str().ok())
+        .map(str::to_string);
+    let dns_names = cert
+        .subject_alternative_name()
+        .ok()
+        .flatten()
+        .map(|san| {
+            san.value
+                .general_names
+                .iter()
+                .filter_map(|name| match name {
+                    GeneralName::DNSName(dns) => Some((*dns).to_string()),
+                    _ => None,
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+
+    Some(ClientIdentity {
+        common_name,
+        dns_names,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn missing_cert_file_should_name_the_path() {
+        let settings = TlsSettings {
+            cert: PathBuf::from("/nonexistent/server.pem"),
+            key: PathBuf::from("/nonexistent/server.key"),
+            client_ca: None,
+        };
+        let err = settings.load().unwrap_err();
+        assert!(err.to_string().contains("/nonexistent/server.pem"));
+    }
+
+    #[test]
+    fn non_pem_file_should_be_rejected() {
+        let path = std::env::temp_dir().join("rsvp-tls-not-pem");
+        std::fs::write(&path, b"certainly not a certificate").unwrap();
+        let err = read_pem(&path).unwrap_err();
+        assert_eq!(err.kind(), std::io::ErrorKind::InvalidData);
+        assert!(err.to_string().contains("no PEM data"));
+        std::fs::remove_file(&path).ok();
+    }
+
+    #[test]
+    fn plaintext_request_should_have_no_identity() {
+        let request = Request::new(());
+        assert!(client_identity(&request).is_none());
+    }
+}